@@ -0,0 +1,372 @@
+//! Procedural macros for [chainer](https://docs.rs/chainer). Use these through the `macros` feature of `chainer` rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+	parse_macro_input, parse_quote, spanned::Spanned, visit::Visit, visit_mut::VisitMut, DeriveInput, FnArg, ImplItem, ImplItemFn, ItemImpl, Pat, PatIdent, PatType, ReturnType, Type,
+	Visibility
+};
+
+/// Generates chain-friendly versions of the `&self` and `&mut self` methods of an `impl` block.
+#[proc_macro_attribute]
+pub fn chainable(attr: TokenStream, item: TokenStream) -> TokenStream {
+	let vis = parse_macro_input!(attr as Visibility);
+	let item = parse_macro_input!(item as ItemImpl);
+
+	match expand(vis, &item) {
+		Ok(chainable) => quote!(#item #chainable).into(),
+		Err(err) => {
+			let err = err.into_compile_error();
+			quote!(#item #err).into()
+		}
+	}
+}
+
+/// Implements `Chainable` for a type, opting it in to call chaining when the `blanket` feature of `chainer` is disabled.
+#[proc_macro_derive(Chainable)]
+pub fn derive_chainable(item: TokenStream) -> TokenStream {
+	let item = parse_macro_input!(item as DeriveInput);
+	let ident = &item.ident;
+	let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+	quote!(impl #impl_generics ::chainer::Chainable for #ident #ty_generics #where_clause {}).into()
+}
+
+/// How the result of a method is kept by the generated `chain_{method}_result` method.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kept {
+	/// The result doesn't borrow from the subject, and is kept as is.
+	Value,
+	/// The result is a shared reference into the subject, and is kept through `chain_ref`.
+	Ref,
+	/// The result is a mutable reference into the subject, and is kept through `chain_mut_ref`, which ends the call chain.
+	RefMut,
+	/// The result borrows from the subject in some other way, and can't be kept, so no method is generated.
+	Never
+}
+
+struct Method<'a> {
+	item: &'a ImplItemFn,
+	mutable: bool,
+	args: Vec<(syn::Ident, Type)>,
+	output: Type,
+	kept: Kept
+}
+
+fn expand(vis: Visibility, item: &ItemImpl) -> syn::Result<TokenStream2> {
+	if let Some((_, path, _)) = &item.trait_ {
+		return Err(syn::Error::new(path.span(), "#[chainable] can only be used on inherent impl blocks"));
+	}
+
+	let self_ty = &*item.self_ty;
+	let type_name = match self_ty {
+		Type::Path(path) if path.qself.is_none() => &path.path.segments.last().expect("type path has no segments").ident,
+		_ => return Err(syn::Error::new(self_ty.span(), "#[chainable] can only be used on impl blocks for named types"))
+	};
+
+	let methods = item
+		.items
+		.iter()
+		.filter_map(|item| match item {
+			ImplItem::Fn(item) => Method::new(item, self_ty).transpose(),
+			_ => None
+		})
+		.collect::<syn::Result<Vec<_>>>()?;
+
+	let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+	let trait_generics = &item.generics;
+
+	let chain_trait = format_ident!("{}Chain", type_name);
+	let chain_trait_doc = format!("Chain-friendly versions of the methods of [`{type_name}`], generated by `#[chainable]`.");
+	let chain_methods = methods.iter().map(|method| method.signature(&format_ident!("chain_{}", method.item.sig.ident), method.chain_receiver(), method.chain_output()));
+	let chain_bodies = methods.iter().map(|method| {
+		let signature = method.signature(&format_ident!("chain_{}", method.item.sig.ident), method.chain_receiver(), method.chain_output());
+		let call = method.call(self_ty);
+		let chain = if method.mutable { quote!(chain_mut) } else { quote!(chain) };
+		let call_chain = if method.mutable { quote!(::chainer::CallChainMut) } else { quote!(::chainer::CallChain) };
+		quote! {
+			#[inline]
+			#signature {
+				#call_chain::#chain(self, move |this| {
+					let _ = #call;
+				})
+			}
+		}
+	});
+
+	let mut expanded = quote! {
+		#[doc = #chain_trait_doc]
+		#vis trait #chain_trait #trait_generics #where_clause {
+			#(#chain_methods;)*
+		}
+
+		impl #impl_generics #chain_trait #ty_generics for #self_ty #where_clause {
+			#(#chain_bodies)*
+		}
+	};
+
+	if cfg!(feature = "results") {
+		let lifetime = syn::Lifetime::new("'__chainer", Span::call_site());
+		let result = format_ident!("__ChainerResult");
+
+		let mut trait_generics = item.generics.clone();
+		trait_generics.params.insert(0, parse_quote!(#lifetime));
+		trait_generics.make_where_clause().predicates.push(parse_quote!(#self_ty: #lifetime));
+		let (impl_generics, ty_generics, where_clause) = trait_generics.split_for_impl();
+
+		let mut result_generics = trait_generics.clone();
+		result_generics.params.push(parse_quote!(#result));
+		let (result_impl_generics, _, _) = result_generics.split_for_impl();
+
+		let result_methods = methods.iter().filter(|method| method.kept != Kept::Never).collect::<Vec<_>>();
+		let shared_methods = result_methods.iter().filter(|method| !method.mutable).collect::<Vec<_>>();
+
+		let result_trait = format_ident!("{}ChainResult", type_name);
+		let result_trait_doc = format!("Chain-friendly versions of the methods of [`{type_name}`] with access to their results, generated by `#[chainable]`.");
+		let result_signatures = result_methods.iter().map(|method| method.result_signature(self_ty, &lifetime, true));
+		let subject_bodies = result_methods.iter().map(|method| {
+			let chain = match method.kept {
+				Kept::Value => quote!(::chainer::ResultChainMut::chain_mut_result),
+				Kept::Ref => quote!(<#self_ty as ::chainer::ResultChain>::chain_ref),
+				Kept::RefMut => quote!(::chainer::ResultChainMut::chain_mut_ref),
+				Kept::Never => unreachable!()
+			};
+			method.result_body(self_ty, &lifetime, true, chain, quote!(self))
+		});
+		let result_bodies = result_methods.iter().map(|method| {
+			let chain = match method.kept {
+				Kept::Value if method.mutable => quote!(chain_mut_result),
+				Kept::Value => quote!(chain_result),
+				Kept::Ref => quote!(chain_ref),
+				Kept::RefMut => quote!(chain_mut_ref),
+				Kept::Never => unreachable!()
+			};
+			method.result_body(self_ty, &lifetime, true, quote!(::chainer::CallChainResultMut::#chain), quote!(self))
+		});
+
+		let shared_trait = format_ident!("{}ChainResultShared", type_name);
+		let shared_trait_doc = format!("Chain-friendly versions of the `&self` methods of [`{type_name}`] with access to their results, generated by `#[chainable]`.");
+		let shared_signatures = shared_methods.iter().map(|method| method.result_signature(self_ty, &lifetime, false));
+		let shared_subject_bodies = shared_methods.iter().map(|method| {
+			let chain = if method.kept == Kept::Ref { quote!(chain_ref) } else { quote!(chain_result) };
+			method.result_body(self_ty, &lifetime, false, quote!(::chainer::ResultChain::#chain), quote!(self))
+		});
+		let shared_bodies = shared_methods.iter().map(|method| {
+			let chain = if method.kept == Kept::Ref { quote!(chain_ref) } else { quote!(chain_result) };
+			method.result_body(self_ty, &lifetime, false, quote!(::chainer::CallChainResult::#chain), quote!(&self))
+		});
+
+		expanded.extend(quote! {
+			#[doc = #result_trait_doc]
+			#vis trait #result_trait #trait_generics #where_clause {
+				#(#result_signatures;)*
+			}
+
+			impl #impl_generics #result_trait #ty_generics for &#lifetime mut #self_ty #where_clause {
+				#(#subject_bodies)*
+			}
+
+			impl #result_impl_generics #result_trait #ty_generics for ::chainer::CallChainResultMut<#lifetime, #self_ty, #result> #where_clause {
+				#(#result_bodies)*
+			}
+
+			#[doc = #shared_trait_doc]
+			#vis trait #shared_trait #trait_generics #where_clause {
+				#(#shared_signatures;)*
+			}
+
+			impl #impl_generics #shared_trait #ty_generics for &#lifetime #self_ty #where_clause {
+				#(#shared_subject_bodies)*
+			}
+
+			impl #result_impl_generics #shared_trait #ty_generics for ::chainer::CallChainResult<#lifetime, #self_ty, #result> #where_clause {
+				#(#shared_bodies)*
+			}
+		});
+	}
+
+	Ok(expanded)
+}
+
+impl<'a> Method<'a> {
+	/// Returns `None` for methods which don't take `&self` or `&mut self`.
+	fn new(item: &'a ImplItemFn, self_ty: &Type) -> syn::Result<Option<Self>> {
+		let mutable = match item.sig.receiver() {
+			Some(receiver) if receiver.reference.is_some() && receiver.colon_token.is_none() => receiver.mutability.is_some(),
+			_ => return Ok(None)
+		};
+
+		if let Some(asyncness) = item.sig.asyncness {
+			return Err(syn::Error::new(asyncness.span, "#[chainable] does not support async methods, use `chain_async` instead"));
+		}
+
+		let args = item
+			.sig
+			.inputs
+			.iter()
+			.filter_map(|arg| match arg {
+				FnArg::Typed(PatType { pat, ty, .. }) => Some((pat, ty)),
+				FnArg::Receiver(_) => None
+			})
+			.enumerate()
+			.map(|(i, (pat, ty))| {
+				let ident = match &**pat {
+					Pat::Ident(PatIdent { ident, .. }) => format_ident!("__chainer_{}", ident),
+					_ => format_ident!("__chainer_arg{}", i)
+				};
+				(ident, replace_self(ty, self_ty))
+			})
+			.collect();
+
+		let output = match &item.sig.output {
+			ReturnType::Default => parse_quote!(()),
+			ReturnType::Type(_, ty) => replace_self(ty, self_ty)
+		};
+
+		let kept = kept(&output, mutable, self_ty);
+
+		Ok(Some(Method { item, mutable, args, output, kept }))
+	}
+
+	fn signature(&self, ident: &syn::Ident, receiver: TokenStream2, output: TokenStream2) -> TokenStream2 {
+		let doc = format!("Chain-friendly version of `{}`.", self.item.sig.ident);
+		let generics = &self.item.sig.generics;
+		let where_clause = &generics.where_clause;
+		let args = self.args.iter().map(|(ident, ty)| quote!(#ident: #ty));
+		quote! {
+			#[doc = #doc]
+			fn #ident #generics(#receiver, #(#args),*) -> #output #where_clause
+		}
+	}
+
+	fn chain_receiver(&self) -> TokenStream2 {
+		if self.mutable {
+			quote!(&mut self)
+		} else {
+			quote!(&self)
+		}
+	}
+
+	fn chain_output(&self) -> TokenStream2 {
+		if self.mutable {
+			quote!(&mut Self)
+		} else {
+			quote!(&Self)
+		}
+	}
+
+	/// The signature of the `chain_{method}_result` method, which continues a mutable call chain if `mutable_chain` is `true`.
+	fn result_signature(&self, self_ty: &Type, lifetime: &syn::Lifetime, mutable_chain: bool) -> TokenStream2 {
+		let mut output = self.output.clone();
+		if let Type::Reference(reference) = &mut output {
+			reference.lifetime = Some(lifetime.clone());
+		}
+
+		let output = match self.kept {
+			Kept::Value if mutable_chain => quote!(::chainer::CallChainResultMut<#lifetime, #self_ty, #output>),
+			Kept::Value | Kept::Ref => quote!(::chainer::CallChainResult<#lifetime, #self_ty, #output>),
+			Kept::RefMut | Kept::Never => quote!(#output)
+		};
+		self.signature(&format_ident!("chain_{}_result", self.item.sig.ident), quote!(self), output)
+	}
+
+	fn result_body(&self, self_ty: &Type, lifetime: &syn::Lifetime, mutable_chain: bool, chain: TokenStream2, receiver: TokenStream2) -> TokenStream2 {
+		let signature = self.result_signature(self_ty, lifetime, mutable_chain);
+		let call = self.call(self_ty);
+		quote! {
+			#[inline]
+			#signature {
+				#chain(#receiver, move |this| #call)
+			}
+		}
+	}
+
+	fn call(&self, self_ty: &Type) -> TokenStream2 {
+		let ident = &self.item.sig.ident;
+		let args = self.args.iter().map(|(ident, _)| ident);
+		quote!(<#self_ty>::#ident(this, #(#args),*))
+	}
+}
+
+/// Replaces `Self` in a method signature with the type of the impl block, as `Self` refers to a different type in the generated traits.
+fn replace_self(ty: &Type, self_ty: &Type) -> Type {
+	struct ReplaceSelf<'a>(&'a Type);
+	impl VisitMut for ReplaceSelf<'_> {
+		fn visit_type_mut(&mut self, ty: &mut Type) {
+			match ty {
+				Type::Path(path) if path.qself.is_none() && path.path.is_ident("Self") => *ty = self.0.clone(),
+				_ => syn::visit_mut::visit_type_mut(self, ty)
+			}
+		}
+	}
+
+	let mut ty = ty.clone();
+	ReplaceSelf(self_ty).visit_type_mut(&mut ty);
+	ty
+}
+
+/// Decides how the result of a method returning `output` is kept.
+fn kept(output: &Type, mutable: bool, self_ty: &Type) -> Kept {
+	match output {
+		Type::Reference(reference) if is_elided(reference.lifetime.as_ref()) && !borrows_elided(&reference.elem, self_ty) => match (mutable, reference.mutability.is_some()) {
+			(false, false) => Kept::Ref,
+			(true, true) => Kept::RefMut,
+			_ => Kept::Never
+		},
+		output if borrows_elided(output, self_ty) => Kept::Never,
+		_ => Kept::Value
+	}
+}
+
+fn is_elided(lifetime: Option<&syn::Lifetime>) -> bool {
+	match lifetime {
+		Some(lifetime) => lifetime.ident == "_",
+		None => true
+	}
+}
+
+/// Generic types which are known to have no lifetime parameters, so they can't hide an elided lifetime in their generic arguments.
+const OWNED_GENERICS: &[&str] = &[
+	"Option", "Result", "Vec", "VecDeque", "LinkedList", "BinaryHeap", "HashMap", "HashSet", "BTreeMap", "BTreeSet", "Box", "Rc", "Arc", "Cell", "RefCell",
+	"Mutex", "RwLock", "PhantomData", "Wrapping", "Saturating", "Reverse", "ManuallyDrop", "MaybeUninit", "Poll", "ControlFlow", "Bound"
+];
+
+/// Returns whether a type may contain an elided lifetime, i.e. whether a method returning it may borrow from `self`.
+///
+/// Besides written `&` and `'_` lifetimes, generic types may hide elided lifetimes, e.g. `Iter<T>`, as may `impl Trait` types. Unless the generic type is known to have no lifetime parameters, or has its lifetimes written out, it is assumed to borrow.
+fn borrows_elided(ty: &Type, self_ty: &Type) -> bool {
+	struct BorrowsElided(bool, String);
+	impl<'ast> Visit<'ast> for BorrowsElided {
+		fn visit_type(&mut self, ty: &'ast Type) {
+			// The type of the impl block can't hide an elided lifetime, as impl headers must write out their lifetimes.
+			if quote!(#ty).to_string() != self.1 {
+				syn::visit::visit_type(self, ty);
+			}
+		}
+
+		fn visit_type_reference(&mut self, reference: &'ast syn::TypeReference) {
+			self.0 |= is_elided(reference.lifetime.as_ref());
+			syn::visit::visit_type_reference(self, reference);
+		}
+
+		fn visit_type_impl_trait(&mut self, _: &'ast syn::TypeImplTrait) {
+			self.0 = true;
+		}
+
+		fn visit_path_segment(&mut self, segment: &'ast syn::PathSegment) {
+			if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+				let has_lifetimes = arguments.args.iter().any(|argument| matches!(argument, syn::GenericArgument::Lifetime(_)));
+				self.0 |= !has_lifetimes && !OWNED_GENERICS.iter().any(|owned| segment.ident == owned);
+			}
+			syn::visit::visit_path_segment(self, segment);
+		}
+
+		fn visit_lifetime(&mut self, lifetime: &'ast syn::Lifetime) {
+			self.0 |= is_elided(Some(lifetime));
+		}
+	}
+
+	let mut visitor = BorrowsElided(false, quote!(#self_ty).to_string());
+	visitor.visit_type(ty);
+	visitor.0
+}
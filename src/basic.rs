@@ -1,3 +1,9 @@
+use core::future::Future;
+
+use crate::{AsyncCallChain, AsyncCallChainMut, ChainObserver, ChainTry, Chained, FocusCallChain, ObservedCallChain, ObservedCallChainMut, Snapshot, TryCallChain, TryCallChainMut, repeat, transaction};
+#[cfg(feature = "std")]
+use crate::{CatchCallChain, CatchCallChainMut, CatchPolicy};
+
 /// Enables immutable call chaining on all types.
 ///
 /// # Example
@@ -55,6 +61,240 @@ pub trait CallChain {
 	}
 }
 
+/// Enables fallible, conditional, repeated, inspected, observed and asynchronous immutable call chaining on all types.
+///
+/// Exported from both the crate root and [`prelude`](crate::prelude), so that it can be used alongside either [`CallChain`] or [`CallChainShared`].
+pub trait CallChainExt {
+	/// Enables fallible immutable call chaining on all types.
+	///
+	/// The chain stops at the first step that returns `Err` or `None`, and reports the zero-based index of that step.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Config { port: Option<u16> }
+	/// impl Config {
+	///     fn validate(&self) -> Result<(), &'static str> {
+	///         Ok(())
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let error = Config { port: None }
+	///         .try_chain(Config::validate)
+	///         .try_chain(|config| config.port.ok_or("missing port"))
+	///         .try_chain(Config::validate)
+	///         .into_result()
+	///         .err()
+	///         .unwrap();
+	///
+	///     assert_eq!(error.step, 1);
+	///     assert_eq!(error.error, "missing port");
+	/// }
+	/// ```
+	fn try_chain<R: ChainTry, F: FnOnce(&Self) -> R>(&self, f: F) -> TryCallChain<'_, Self, R::Output, R::Error> {
+		TryCallChain::new(self, f(self))
+	}
+
+	#[cfg(feature = "std")]
+	/// Enables immutable call chaining which catches panics, on all types.
+	///
+	/// Every step of the returned call chain runs under [`catch_unwind`](std::panic::catch_unwind), and `policy` decides whether the steps after a panic run.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Parser;
+	/// impl Parser {
+	///     fn parse(&self) -> u8 {
+	///         "256".parse().unwrap()
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let (_, panics) = Parser
+	///         .chain_catch(CatchPolicy::Continue, Parser::parse)
+	///         .chain_catch(|_| "still runs")
+	///         .into_parts();
+	///
+	///     assert_eq!(panics.len(), 1);
+	///     assert_eq!(panics[0].step, 0);
+	/// }
+	/// ```
+	fn chain_catch<R, F: FnOnce(&Self) -> R>(&self, policy: CatchPolicy, f: F) -> CatchCallChain<'_, Self, R> {
+		CatchCallChain::new(self, policy, f)
+	}
+
+	/// Enables conditional immutable call chaining on all types.
+	///
+	/// `f` is only called if `condition` is `true`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Logger;
+	/// impl Logger {
+	///     fn log(&self) {
+	///         println!("Hello, world!");
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let verbose = false;
+	///
+	///     Logger
+	///         .chain(Logger::log)
+	///         .chain_if(verbose, Logger::log);
+	///
+	///     // Hello, world!
+	/// }
+	/// ```
+	fn chain_if<R, F: FnOnce(&Self) -> R>(&self, condition: bool, f: F) -> &Self {
+		if condition {
+			f(self);
+		}
+		self
+	}
+
+	/// Enables conditional immutable call chaining on all types.
+	///
+	/// `f` is only called if `predicate` returns `true` for the chained subject.
+	fn chain_when<P: FnOnce(&Self) -> bool, R, F: FnOnce(&Self) -> R>(&self, predicate: P, f: F) -> &Self {
+		if predicate(self) {
+			f(self);
+		}
+		self
+	}
+
+	/// Enables repeated immutable call chaining on all types.
+	///
+	/// `f` is called `n` times.
+	fn chain_n<R, F: FnMut(&Self) -> R>(&self, n: usize, f: F) -> &Self {
+		repeat::repeat_n(self, n, f);
+		self
+	}
+
+	#[cfg(feature = "std")]
+	#[track_caller]
+	/// Prints the [`Debug`](core::fmt::Debug) output of the chained subject to stderr, along with the file and line of the call, like [`dbg!`](std::dbg).
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// #[derive(Debug)]
+	/// struct Counter { value: i32 }
+	///
+	/// fn main() {
+	///     Counter { value: 0 }
+	///         .chain_dbg()
+	///         .chain_debug_only(|counter| assert_eq!(counter.value, 0));
+	///
+	///     // [src/main.rs:8:10] Counter {
+	///     //     value: 0,
+	///     // }
+	/// }
+	/// ```
+	fn chain_dbg(&self) -> &Self
+	where
+		Self: core::fmt::Debug
+	{
+		crate::inspect::dbg(core::panic::Location::caller(), self);
+		self
+	}
+
+	/// Enables immutable call chaining in debug builds only.
+	///
+	/// `f` is only called when `debug_assertions` are enabled, and is compiled away otherwise.
+	fn chain_debug_only<R, F: FnOnce(&Self) -> R>(&self, f: F) -> &Self {
+		#[cfg(debug_assertions)]
+		f(self);
+		#[cfg(not(debug_assertions))]
+		let _ = f;
+		self
+	}
+
+	/// Starts an immutable call chain which reports each of its steps to `observer`.
+	///
+	/// See [`ChainObserver`] for an example.
+	fn observe<O: ChainObserver>(&self, observer: O) -> ObservedCallChain<'_, Self, O> {
+		ObservedCallChain::new(self, observer)
+	}
+
+	/// Enables asynchronous immutable call chaining on all types.
+	///
+	/// Returns a future which awaits every chained function in order and resolves to the chained subject.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Client;
+	/// impl Client {
+	///     async fn ping(&self) -> &'static str {
+	///         "pong"
+	///     }
+	/// }
+	///
+	/// async fn run(client: &Client) {
+	///     let client: &Client = client
+	///         .chain_async(Client::ping)
+	///         .chain_async(Client::ping)
+	///         .await;
+	/// }
+	/// ```
+	fn chain_async<R, F: AsyncFnOnce(&Self) -> R>(&self, f: F) -> AsyncCallChain<'_, Self, impl Future<Output = (&Self, R)>> {
+		AsyncCallChain::new(async move {
+			let result = f(self).await;
+			(self, result)
+		})
+	}
+}
+
+/// Enables immutable call chaining on all types, under names which don't collide with [`Iterator`].
+///
+/// [`CallChain::chain`] shares its name with [`Iterator::chain`]. This trait is only exported from [`prelude`](crate::prelude), so that importing the prelude instead of the crate root leaves [`Iterator::chain`] unambiguous.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::prelude::*;
+///
+/// struct HelloWorld;
+/// impl HelloWorld {
+///     fn print(&self) {
+///         println!("Hello, world!");
+///     }
+/// }
+///
+/// fn main() {
+///     HelloWorld
+///         .chain_shared(HelloWorld::print)
+///         .chain_shared(HelloWorld::print);
+///
+///     let numbers: Vec<i32> = [1, 2].into_iter().chain([3]).collect();
+///     assert_eq!(numbers, [1, 2, 3]);
+///
+///     // Hello, world!
+///     // Hello, world!
+/// }
+/// ```
+pub trait CallChainShared {
+	/// Enables immutable call chaining on all types. Equivalent to [`CallChain::chain`].
+	fn chain_shared<R, F: FnOnce(&Self) -> R>(&self, f: F) -> &Self {
+		f(self);
+		self
+	}
+}
+
 /// Enables mutable call chaining on all types.
 ///
 /// # Example
@@ -112,7 +352,318 @@ pub trait CallChainMut {
 		f(self);
 		self
 	}
+
+	/// Enables fallible mutable call chaining on all types.
+	///
+	/// The chain stops at the first step that returns `Err` or `None`, and reports the zero-based index of that step.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Counter { value: u8 }
+	/// impl Counter {
+	///     fn increment(&mut self) -> Option<u8> {
+	///         self.value = self.value.checked_add(1)?;
+	///         Some(self.value)
+	///     }
+	/// }
+	///
+	/// fn main() -> Result<(), ChainError<NoneError>> {
+	///     let mut counter = Counter { value: 0 };
+	///
+	///     counter
+	///         .try_chain_mut(Counter::increment)
+	///         .try_chain_mut(Counter::increment)
+	///         .into_result()?;
+	///
+	///     assert_eq!(counter.value, 2);
+	///
+	///     let error = Counter { value: 254 }
+	///         .try_chain_mut(Counter::increment)
+	///         .try_chain_mut(Counter::increment)
+	///         .into_result()
+	///         .err()
+	///         .unwrap();
+	///
+	///     assert_eq!(error.step, 1);
+	///
+	///     Ok(())
+	/// }
+	/// ```
+	fn try_chain_mut<R: ChainTry, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> TryCallChainMut<'_, Self, R::Output, R::Error> {
+		let result = f(self);
+		TryCallChainMut::new(self, result)
+	}
+
+	/// Enables transactional mutable call chaining on all types.
+	///
+	/// `f` runs a fallible call chain on the chained subject, which is cloned beforehand and restored from the clone if any step fails or panics. The returned fallible call chain continues after the transaction.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// #[derive(Clone)]
+	/// struct Counter { value: u8 }
+	/// impl Counter {
+	///     fn add(&mut self, amount: u8) -> Option<()> {
+	///         self.value = self.value.checked_add(amount)?;
+	///         Some(())
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let mut counter = Counter { value: 0 };
+	///
+	///     let committed = counter
+	///         .transaction(|tx| tx.chain_mut(|counter| counter.value = 100).try_chain_mut(|counter| counter.add(100)))
+	///         .into_result()
+	///         .is_ok();
+	///
+	///     assert!(committed);
+	///     assert_eq!(counter.value, 200);
+	///
+	///     let committed = counter
+	///         .transaction(|tx| tx.try_chain_mut(|counter| counter.add(50)).try_chain_mut(|counter| counter.add(50)))
+	///         .into_result()
+	///         .is_ok();
+	///
+	///     assert!(!committed);
+	///     assert_eq!(counter.value, 200);
+	/// }
+	/// ```
+	fn transaction<R, E, F>(&mut self, f: F) -> TryCallChainMut<'_, Self, R, E>
+	where
+		Self: Clone,
+		F: for<'t> FnOnce(&'t mut Self) -> TryCallChainMut<'t, Self, R, E>
+	{
+		let (step, result) = transaction::run(self, self.clone(), |this, snapshot| *this = snapshot, f);
+		TryCallChainMut { this: self, step, result }
+	}
+
+	/// Enables transactional mutable call chaining on all types.
+	///
+	/// Like [`CallChainMut::transaction`], but the chained subject is saved and restored with its [`Snapshot`] implementation.
+	fn transaction_snapshot<R, E, F>(&mut self, f: F) -> TryCallChainMut<'_, Self, R, E>
+	where
+		Self: Snapshot,
+		F: for<'t> FnOnce(&'t mut Self) -> TryCallChainMut<'t, Self, R, E>
+	{
+		let (step, result) = transaction::run(self, self.snapshot(), Self::restore, f);
+		TryCallChainMut { this: self, step, result }
+	}
+
+	#[cfg(feature = "std")]
+	/// Enables mutable call chaining which catches panics, on all types.
+	///
+	/// Every step of the returned call chain runs under [`catch_unwind`](std::panic::catch_unwind), and `policy` decides whether the steps after a panic run.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Counter { value: u8 }
+	/// impl Counter {
+	///     fn increment(&mut self) {
+	///         self.value = self.value.checked_add(1).expect("overflow");
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let mut counter = Counter { value: 254 };
+	///
+	///     let (_, panics) = counter
+	///         .chain_mut_catch(CatchPolicy::Stop, Counter::increment)
+	///         .chain_mut_catch(Counter::increment)
+	///         .chain_mut_catch(Counter::increment)
+	///         .into_parts();
+	///
+	///     assert_eq!(counter.value, 255);
+	///     assert_eq!(panics[0].step, 1);
+	///     assert_eq!(panics[0].message(), Some("overflow"));
+	/// }
+	/// ```
+	fn chain_mut_catch<R, F: FnOnce(&mut Self) -> R>(&mut self, policy: CatchPolicy, f: F) -> CatchCallChainMut<'_, Self, R> {
+		CatchCallChainMut::new(self, policy, f)
+	}
+
+	/// Enables conditional mutable call chaining on all types.
+	///
+	/// `f` is only called if `condition` is `true`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Counter { value: i32 }
+	/// impl Counter {
+	///     fn increment(&mut self) {
+	///         self.value += 1;
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let double_increment = false;
+	///     let mut counter = Counter { value: 0 };
+	///
+	///     counter
+	///         .chain_mut(Counter::increment)
+	///         .chain_mut_if(double_increment, Counter::increment)
+	///         .chain_mut_when(|counter| counter.value < 5, Counter::increment);
+	///
+	///     assert_eq!(counter.value, 2);
+	/// }
+	/// ```
+	fn chain_mut_if<R, F: FnOnce(&mut Self) -> R>(&mut self, condition: bool, f: F) -> &mut Self {
+		if condition {
+			f(self);
+		}
+		self
+	}
+
+	/// Enables conditional mutable call chaining on all types.
+	///
+	/// `f` is only called if `predicate` returns `true` for the chained subject.
+	fn chain_mut_when<P: FnOnce(&Self) -> bool, R, F: FnOnce(&mut Self) -> R>(&mut self, predicate: P, f: F) -> &mut Self {
+		if predicate(self) {
+			f(self);
+		}
+		self
+	}
+
+	/// Enables repeated mutable call chaining on all types.
+	///
+	/// `f` is called `n` times.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// #[derive(Clone, PartialEq)]
+	/// struct Counter { value: i32 }
+	/// impl Counter {
+	///     fn increment(&mut self) {
+	///         self.value += 1;
+	///     }
+	///     fn increment_to_ten(&mut self) {
+	///         self.value = (self.value + 1).min(10);
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let mut counter = Counter { value: 0 };
+	///
+	///     counter.chain_mut_n(3, Counter::increment);
+	///     assert_eq!(counter.value, 3);
+	///
+	///     counter.chain_mut_while(|counter| counter.value < 5, Counter::increment);
+	///     assert_eq!(counter.value, 5);
+	///
+	///     counter.chain_mut_until_fixpoint(Counter::increment_to_ten);
+	///     assert_eq!(counter.value, 10);
+	/// }
+	/// ```
+	fn chain_mut_n<R, F: FnMut(&mut Self) -> R>(&mut self, n: usize, f: F) -> &mut Self {
+		repeat::repeat_n_mut(self, n, f);
+		self
+	}
+
+	/// Enables repeated mutable call chaining on all types.
+	///
+	/// `f` is called for as long as `predicate` returns `true` for the chained subject.
+	fn chain_mut_while<P: FnMut(&Self) -> bool, R, F: FnMut(&mut Self) -> R>(&mut self, predicate: P, f: F) -> &mut Self {
+		repeat::repeat_while_mut(self, predicate, f);
+		self
+	}
+
+	/// Enables repeated mutable call chaining on all types.
+	///
+	/// `f` is called until the chained subject stops changing.
+	fn chain_mut_until_fixpoint<R, F: FnMut(&mut Self) -> R>(&mut self, f: F) -> &mut Self
+	where
+		Self: PartialEq + Clone
+	{
+		repeat::repeat_until_fixpoint_mut(self, f);
+		self
+	}
+
+	#[cfg(feature = "std")]
+	#[track_caller]
+	/// Prints the [`Debug`](core::fmt::Debug) output of the chained subject to stderr, along with the file and line of the call, like [`dbg!`](std::dbg).
+	fn chain_mut_dbg(&mut self) -> &mut Self
+	where
+		Self: core::fmt::Debug
+	{
+		crate::inspect::dbg(core::panic::Location::caller(), self);
+		self
+	}
+
+	/// Enables mutable call chaining in debug builds only.
+	///
+	/// `f` is only called when `debug_assertions` are enabled, and is compiled away otherwise.
+	fn chain_mut_debug_only<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> &mut Self {
+		#[cfg(debug_assertions)]
+		f(self);
+		#[cfg(not(debug_assertions))]
+		let _ = f;
+		self
+	}
+
+	/// Focuses the call chain on a part of the chained subject, such as a field.
+	///
+	/// The returned call chain runs its steps on the part returned by `focus`. Calling [`FocusCallChain::back`] returns to the chained subject.
+	fn focus_mut<T: ?Sized, G: Fn(&mut Self) -> &mut T>(&mut self, focus: G) -> FocusCallChain<'_, Self, T, G> {
+		FocusCallChain::new(self, focus, ())
+	}
+
+	/// Starts a mutable call chain which reports each of its steps to `observer`.
+	///
+	/// See [`ChainObserver`] for an example.
+	fn observe_mut<O: ChainObserver>(&mut self, observer: O) -> ObservedCallChainMut<'_, Self, O> {
+		ObservedCallChainMut::new(self, observer)
+	}
+
+	/// Enables asynchronous mutable call chaining on all types.
+	///
+	/// Returns a future which awaits every chained function in order and resolves to the chained subject.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Client { sent: u32 }
+	/// impl Client {
+	///     async fn send(&mut self) -> u32 {
+	///         self.sent += 1;
+	///         self.sent
+	///     }
+	/// }
+	///
+	/// async fn run(client: &mut Client) {
+	///     client
+	///         .chain_mut_async(Client::send)
+	///         .chain_mut_async(Client::send)
+	///         .await;
+	///
+	///     assert_eq!(client.sent, 2);
+	/// }
+	/// ```
+	fn chain_mut_async<R, F: AsyncFnOnce(&mut Self) -> R>(&mut self, f: F) -> AsyncCallChainMut<'_, Self, impl Future<Output = (&mut Self, R)>> {
+		AsyncCallChainMut::new(async move {
+			let result = f(&mut *self).await;
+			(self, result)
+		})
+	}
 }
 
-impl<T: ?Sized> CallChain for T {}
-impl<T: ?Sized> CallChainMut for T {}
\ No newline at end of file
+impl<T: ?Sized + Chained> CallChain for T {}
+impl<T: ?Sized + Chained> CallChainExt for T {}
+impl<T: ?Sized + Chained> CallChainShared for T {}
+impl<T: ?Sized + Chained> CallChainMut for T {}
\ No newline at end of file
@@ -0,0 +1,222 @@
+use std::{any::Any, boxed::Box, panic, string::String, vec::Vec};
+
+/// What a catching call chain does with its remaining steps after one of them panics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CatchPolicy {
+	/// Skip every following step.
+	#[default]
+	Stop,
+
+	/// Keep running the following steps.
+	Continue
+}
+
+/// A panic caught by a catching call chain, identifying which step panicked.
+pub struct ChainPanic {
+	/// The zero-based index of the step that panicked.
+	pub step: usize,
+
+	/// The payload the step panicked with.
+	pub payload: Box<dyn Any + Send + 'static>
+}
+
+impl ChainPanic {
+	/// Returns the panic message, if the step panicked with a string.
+	pub fn message(&self) -> Option<&str> {
+		if let Some(message) = self.payload.downcast_ref::<&'static str>() {
+			Some(message)
+		} else {
+			self.payload.downcast_ref::<String>().map(String::as_str)
+		}
+	}
+
+	/// Resumes unwinding with the caught panic.
+	pub fn resume_unwind(self) -> ! {
+		panic::resume_unwind(self.payload)
+	}
+}
+
+impl core::fmt::Debug for ChainPanic {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("ChainPanic")
+			.field("step", &self.step)
+			.field("message", &self.message())
+			.finish_non_exhaustive()
+	}
+}
+
+impl core::fmt::Display for ChainPanic {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self.message() {
+			Some(message) => write!(f, "call chain panicked at step {}: {}", self.step, message),
+			None => write!(f, "call chain panicked at step {}", self.step)
+		}
+	}
+}
+
+impl core::error::Error for ChainPanic {}
+
+/// Shared bookkeeping of [`CatchCallChain`] and [`CatchCallChainMut`].
+struct Catcher {
+	policy: CatchPolicy,
+	step: usize,
+	panics: Vec<ChainPanic>
+}
+
+impl Catcher {
+	#[inline]
+	fn new(policy: CatchPolicy) -> Self {
+		Catcher { policy, step: 0, panics: Vec::new() }
+	}
+
+	#[inline]
+	fn next(mut self) -> Self {
+		self.step += 1;
+		self
+	}
+
+	/// Runs a step under [`catch_unwind`](panic::catch_unwind), unless the policy says to skip it.
+	fn run<R>(&mut self, f: impl FnOnce() -> R) -> Option<R> {
+		if self.policy == CatchPolicy::Stop && !self.panics.is_empty() {
+			return None;
+		}
+
+		match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+			Ok(result) => Some(result),
+			Err(payload) => {
+				self.panics.push(ChainPanic { step: self.step, payload });
+				None
+			}
+		}
+	}
+
+	#[inline]
+	fn into_result<T>(self, this: T) -> Result<T, Vec<ChainPanic>> {
+		if self.panics.is_empty() { Ok(this) } else { Err(self.panics) }
+	}
+}
+
+/// A call chain which catches panics. Created by [`CallChainExt::chain_catch`](crate::CallChainExt::chain_catch).
+///
+/// Each step runs under [`catch_unwind`](std::panic::catch_unwind). Whether the steps after a panic run is decided by the [`CatchPolicy`]. The chained subject may be left in an inconsistent state by a step which panicked.
+#[must_use = "a catching call chain does nothing with its panics unless ended with `into_result`"]
+pub struct CatchCallChain<'a, S: ?Sized, R> {
+	this: &'a S,
+	catcher: Catcher,
+	#[cfg_attr(not(feature = "results"), allow(dead_code))]
+	result: Option<R>
+}
+
+impl<'a, S: ?Sized, R> CatchCallChain<'a, S, R> {
+	#[inline]
+	pub(crate) fn new<F: FnOnce(&S) -> R>(this: &'a S, policy: CatchPolicy, f: F) -> Self {
+		let mut catcher = Catcher::new(policy);
+		let result = catcher.run(|| f(this));
+		CatchCallChain { catcher, result, this }
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject, catching any panic, continuing the call chain immutably.
+	pub fn chain_catch<U, F: FnOnce(&S) -> U>(self, f: F) -> CatchCallChain<'a, S, U> {
+		let this = self.this;
+		let mut catcher = self.catcher.next();
+		let result = catcher.run(|| f(this));
+		CatchCallChain { catcher, result, this }
+	}
+
+	#[inline]
+	/// Returns the panics caught so far.
+	pub fn panics(&self) -> &[ChainPanic] {
+		&self.catcher.panics
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the chained subject and the panics caught by it.
+	pub fn into_parts(self) -> (&'a S, Vec<ChainPanic>) {
+		(self.this, self.catcher.panics)
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the chained subject, or the caught panics if any step panicked.
+	pub fn into_result(self) -> Result<&'a S, Vec<ChainPanic>> {
+		self.catcher.into_result(self.this)
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Ends the call chain, returning the result of the last chained function, or the caught panics if any step panicked.
+	pub fn into_chain_result(self) -> Result<crate::CallChainResult<'a, S, R>, Vec<ChainPanic>> {
+		let CatchCallChain { this, catcher, result } = self;
+		match (catcher.into_result(this)?, result) {
+			(this, Some(result)) => Ok(crate::CallChainResult { this, result }),
+			(_, None) => unreachable!("a step without a result must have panicked")
+		}
+	}
+}
+
+/// A mutable call chain which catches panics. Created by [`CallChainMut::chain_mut_catch`](crate::CallChainMut::chain_mut_catch).
+///
+/// Each step runs under [`catch_unwind`](std::panic::catch_unwind). Whether the steps after a panic run is decided by the [`CatchPolicy`]. The chained subject may be left half-updated by a step which panicked.
+#[must_use = "a catching call chain does nothing with its panics unless ended with `into_result`"]
+pub struct CatchCallChainMut<'a, S: ?Sized, R> {
+	this: &'a mut S,
+	catcher: Catcher,
+	#[cfg_attr(not(feature = "results"), allow(dead_code))]
+	result: Option<R>
+}
+
+impl<'a, S: ?Sized, R> CatchCallChainMut<'a, S, R> {
+	#[inline]
+	pub(crate) fn new<F: FnOnce(&mut S) -> R>(this: &'a mut S, policy: CatchPolicy, f: F) -> Self {
+		let mut catcher = Catcher::new(policy);
+		let result = catcher.run(|| f(&mut *this));
+		CatchCallChainMut { catcher, result, this }
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject, catching any panic, continuing the call chain immutably.
+	pub fn chain_catch<U, F: FnOnce(&S) -> U>(self, f: F) -> CatchCallChainMut<'a, S, U> {
+		let this = self.this;
+		let mut catcher = self.catcher.next();
+		let result = catcher.run(|| f(&*this));
+		CatchCallChainMut { catcher, result, this }
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject, catching any panic, continuing the call chain mutably.
+	pub fn chain_mut_catch<U, F: FnOnce(&mut S) -> U>(self, f: F) -> CatchCallChainMut<'a, S, U> {
+		let this = self.this;
+		let mut catcher = self.catcher.next();
+		let result = catcher.run(|| f(&mut *this));
+		CatchCallChainMut { catcher, result, this }
+	}
+
+	#[inline]
+	/// Returns the panics caught so far.
+	pub fn panics(&self) -> &[ChainPanic] {
+		&self.catcher.panics
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the chained subject and the panics caught by it.
+	pub fn into_parts(self) -> (&'a mut S, Vec<ChainPanic>) {
+		(self.this, self.catcher.panics)
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the chained subject, or the caught panics if any step panicked.
+	pub fn into_result(self) -> Result<&'a mut S, Vec<ChainPanic>> {
+		self.catcher.into_result(self.this)
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Ends the call chain, returning the result of the last chained function, or the caught panics if any step panicked.
+	pub fn into_chain_result(self) -> Result<crate::CallChainResultMut<'a, S, R>, Vec<ChainPanic>> {
+		let CatchCallChainMut { this, catcher, result } = self;
+		match (catcher.into_result(this)?, result) {
+			(this, Some(result)) => Ok(crate::CallChainResultMut { this, result }),
+			(_, None) => unreachable!("a step without a result must have panicked")
+		}
+	}
+}
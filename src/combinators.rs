@@ -0,0 +1,227 @@
+use crate::{CallChainResult, CallChainResultMut};
+
+impl<'a, S: ?Sized, T> CallChainResult<'a, S, T> {
+	#[inline]
+	/// Maps the result of the chained function with `f`, keeping the chained subject.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Name(&'static str);
+	/// impl Name {
+	///     fn len(&self) -> usize {
+	///         self.0.len()
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let value = Name("chainer")
+	///         .chain_result(Name::len)
+	///         .map_result(|len| len * 2)
+	///         .chain_with(|name, len| name.0.len() + len)
+	///         .result;
+	///
+	///     assert_eq!(value, 21);
+	/// }
+	/// ```
+	pub fn map_result<U, F: FnOnce(T) -> U>(self, f: F) -> CallChainResult<'a, S, U> {
+		CallChainResult {
+			result: f(self.result),
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject and pairs its result with the result of the previous chained function.
+	pub fn zip_result<U, F: FnOnce(&S) -> U>(self, f: F) -> CallChainResult<'a, S, (T, U)> {
+		CallChainResult {
+			result: (self.result, f(self.this)),
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Replaces the result of the chained function with `value`, keeping the chained subject.
+	pub fn replace_result<U>(self, value: U) -> CallChainResult<'a, S, U> {
+		CallChainResult {
+			result: value,
+			this: self.this
+		}
+	}
+}
+
+impl<'a, S: ?Sized, T> CallChainResult<'a, S, Option<T>> {
+	#[inline]
+	/// Calls `f` with the contained value if the result is `Some`, keeping the chained subject.
+	pub fn and_then_result<U, F: FnOnce(T) -> Option<U>>(self, f: F) -> CallChainResult<'a, S, Option<U>> {
+		self.map_result(|result| result.and_then(f))
+	}
+
+	#[inline]
+	/// Unwraps the result, or uses `default` if it is `None`, keeping the chained subject.
+	pub fn unwrap_result_or(self, default: T) -> CallChainResult<'a, S, T> {
+		self.map_result(|result| result.unwrap_or(default))
+	}
+
+	#[inline]
+	/// Unwraps the result, or computes it with `f` if it is `None`, keeping the chained subject.
+	pub fn unwrap_result_or_else<F: FnOnce() -> T>(self, f: F) -> CallChainResult<'a, S, T> {
+		self.map_result(|result| result.unwrap_or_else(f))
+	}
+
+	#[inline]
+	/// Unwraps the result, or uses [`Default::default`] if it is `None`, keeping the chained subject.
+	pub fn unwrap_result_or_default(self) -> CallChainResult<'a, S, T>
+	where
+		T: Default
+	{
+		self.map_result(Option::unwrap_or_default)
+	}
+}
+
+impl<'a, S: ?Sized, T, E> CallChainResult<'a, S, Result<T, E>> {
+	#[inline]
+	/// Calls `f` with the contained value if the result is `Ok`, keeping the chained subject.
+	pub fn and_then_result<U, F: FnOnce(T) -> Result<U, E>>(self, f: F) -> CallChainResult<'a, S, Result<U, E>> {
+		self.map_result(|result| result.and_then(f))
+	}
+
+	#[inline]
+	/// Unwraps the result, or uses `default` if it is `Err`, keeping the chained subject.
+	pub fn unwrap_result_or(self, default: T) -> CallChainResult<'a, S, T> {
+		self.map_result(|result| result.unwrap_or(default))
+	}
+
+	#[inline]
+	/// Unwraps the result, or computes it from the error with `f` if it is `Err`, keeping the chained subject.
+	pub fn unwrap_result_or_else<F: FnOnce(E) -> T>(self, f: F) -> CallChainResult<'a, S, T> {
+		self.map_result(|result| result.unwrap_or_else(f))
+	}
+
+	#[inline]
+	/// Unwraps the result, or uses [`Default::default`] if it is `Err`, keeping the chained subject.
+	pub fn unwrap_result_or_default(self) -> CallChainResult<'a, S, T>
+	where
+		T: Default
+	{
+		self.map_result(Result::unwrap_or_default)
+	}
+}
+
+impl<'a, S: ?Sized, T> CallChainResultMut<'a, S, T> {
+	#[inline]
+	/// Maps the result of the chained function with `f`, keeping the chained subject.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Stack { items: Vec<i32> }
+	/// impl Stack {
+	///     fn pop(&mut self) -> Option<i32> {
+	///         self.items.pop()
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let mut stack = Stack { items: vec![1, 2] };
+	///
+	///     let value = stack
+	///         .chain_mut_result(Stack::pop)
+	///         .map_result(|item| item.map(|item| item * 10))
+	///         .chain_mut_with(|stack, item| stack.pop().zip(item))
+	///         .chain_mut_with(|stack, items| items.and_then(|_| stack.pop()))
+	///         .unwrap_result_or(-1)
+	///         .result;
+	///
+	///     assert_eq!(value, -1);
+	///     assert!(stack.items.is_empty());
+	/// }
+	/// ```
+	pub fn map_result<U, F: FnOnce(T) -> U>(self, f: F) -> CallChainResultMut<'a, S, U> {
+		CallChainResultMut {
+			result: f(self.result),
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject and pairs its result with the result of the previous chained function.
+	pub fn zip_result<U, F: FnOnce(&S) -> U>(self, f: F) -> CallChainResultMut<'a, S, (T, U)> {
+		let result = f(self.this);
+		CallChainResultMut {
+			result: (self.result, result),
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Replaces the result of the chained function with `value`, keeping the chained subject.
+	pub fn replace_result<U>(self, value: U) -> CallChainResultMut<'a, S, U> {
+		CallChainResultMut {
+			result: value,
+			this: self.this
+		}
+	}
+}
+
+impl<'a, S: ?Sized, T> CallChainResultMut<'a, S, Option<T>> {
+	#[inline]
+	/// Calls `f` with the contained value if the result is `Some`, keeping the chained subject.
+	pub fn and_then_result<U, F: FnOnce(T) -> Option<U>>(self, f: F) -> CallChainResultMut<'a, S, Option<U>> {
+		self.map_result(|result| result.and_then(f))
+	}
+
+	#[inline]
+	/// Unwraps the result, or uses `default` if it is `None`, keeping the chained subject.
+	pub fn unwrap_result_or(self, default: T) -> CallChainResultMut<'a, S, T> {
+		self.map_result(|result| result.unwrap_or(default))
+	}
+
+	#[inline]
+	/// Unwraps the result, or computes it with `f` if it is `None`, keeping the chained subject.
+	pub fn unwrap_result_or_else<F: FnOnce() -> T>(self, f: F) -> CallChainResultMut<'a, S, T> {
+		self.map_result(|result| result.unwrap_or_else(f))
+	}
+
+	#[inline]
+	/// Unwraps the result, or uses [`Default::default`] if it is `None`, keeping the chained subject.
+	pub fn unwrap_result_or_default(self) -> CallChainResultMut<'a, S, T>
+	where
+		T: Default
+	{
+		self.map_result(Option::unwrap_or_default)
+	}
+}
+
+impl<'a, S: ?Sized, T, E> CallChainResultMut<'a, S, Result<T, E>> {
+	#[inline]
+	/// Calls `f` with the contained value if the result is `Ok`, keeping the chained subject.
+	pub fn and_then_result<U, F: FnOnce(T) -> Result<U, E>>(self, f: F) -> CallChainResultMut<'a, S, Result<U, E>> {
+		self.map_result(|result| result.and_then(f))
+	}
+
+	#[inline]
+	/// Unwraps the result, or uses `default` if it is `Err`, keeping the chained subject.
+	pub fn unwrap_result_or(self, default: T) -> CallChainResultMut<'a, S, T> {
+		self.map_result(|result| result.unwrap_or(default))
+	}
+
+	#[inline]
+	/// Unwraps the result, or computes it from the error with `f` if it is `Err`, keeping the chained subject.
+	pub fn unwrap_result_or_else<F: FnOnce(E) -> T>(self, f: F) -> CallChainResultMut<'a, S, T> {
+		self.map_result(|result| result.unwrap_or_else(f))
+	}
+
+	#[inline]
+	/// Unwraps the result, or uses [`Default::default`] if it is `Err`, keeping the chained subject.
+	pub fn unwrap_result_or_default(self) -> CallChainResultMut<'a, S, T>
+	where
+		T: Default
+	{
+		self.map_result(Result::unwrap_or_default)
+	}
+}
@@ -0,0 +1,185 @@
+/// A return value of a fallible chained function, such as [`Result`] or [`Option`].
+pub trait ChainTry {
+	/// The value produced by the chained function on success.
+	type Output;
+
+	/// The error produced by the chained function on failure.
+	type Error;
+
+	/// Converts the return value of a chained function into a [`Result`].
+	fn into_chain_step(self) -> Result<Self::Output, Self::Error>;
+}
+
+impl<T, E> ChainTry for Result<T, E> {
+	type Output = T;
+	type Error = E;
+
+	#[inline]
+	fn into_chain_step(self) -> Result<T, E> {
+		self
+	}
+}
+
+impl<T> ChainTry for Option<T> {
+	type Output = T;
+	type Error = NoneError;
+
+	#[inline]
+	fn into_chain_step(self) -> Result<T, NoneError> {
+		self.ok_or(NoneError)
+	}
+}
+
+/// The error reported when a chained function returns `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NoneError;
+
+impl core::fmt::Display for NoneError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str("chained function returned None")
+	}
+}
+
+impl core::error::Error for NoneError {}
+
+/// The error returned by a fallible call chain, identifying which step failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChainError<E> {
+	/// The zero-based index of the step that failed.
+	pub step: usize,
+
+	/// The error returned by the failed step.
+	pub error: E
+}
+
+impl<E> ChainError<E> {
+	#[inline]
+	/// Returns the error returned by the failed step.
+	pub fn into_error(self) -> E {
+		self.error
+	}
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for ChainError<E> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "call chain failed at step {}: {}", self.step, self.error)
+	}
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for ChainError<E> {
+	fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+		Some(&self.error)
+	}
+}
+
+/// A fallible call chain. Created by [`CallChainExt::try_chain`](crate::CallChainExt::try_chain).
+///
+/// Once a step fails, every following step is skipped and the failure is kept until the chain is ended with [`TryCallChain::into_result`].
+#[must_use = "a fallible call chain does nothing with its error unless ended with `into_result`"]
+pub struct TryCallChain<'a, S: ?Sized, R, E> {
+	this: &'a S,
+	step: usize,
+	result: Result<R, ChainError<E>>
+}
+
+impl<'a, S: ?Sized, R, E> TryCallChain<'a, S, R, E> {
+	#[inline]
+	pub(crate) fn new<T: ChainTry<Output = R, Error = E>>(this: &'a S, result: T) -> Self {
+		TryCallChain {
+			this,
+			step: 0,
+			result: result.into_chain_step().map_err(|error| ChainError { step: 0, error })
+		}
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject if no previous step has failed, continuing the fallible call chain immutably.
+	pub fn try_chain<T: ChainTry<Error = E>, F: FnOnce(&S) -> T>(self, f: F) -> TryCallChain<'a, S, T::Output, E> {
+		let step = self.step + 1;
+		TryCallChain {
+			result: match self.result {
+				Ok(_) => f(self.this).into_chain_step().map_err(|error| ChainError { step, error }),
+				Err(err) => Err(err)
+			},
+			this: self.this,
+			step
+		}
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the chained subject or the first failure.
+	pub fn into_result(self) -> Result<&'a S, ChainError<E>> {
+		self.result.map(|_| self.this)
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Ends the call chain, returning the result of the last chained function or the first failure.
+	pub fn into_chain_result(self) -> Result<crate::CallChainResult<'a, S, R>, ChainError<E>> {
+		let this = self.this;
+		self.result.map(|result| crate::CallChainResult { this, result })
+	}
+}
+
+/// A fallible mutable call chain. Created by [`CallChainMut::try_chain_mut`](crate::CallChainMut::try_chain_mut).
+///
+/// Once a step fails, every following step is skipped and the failure is kept until the chain is ended with [`TryCallChainMut::into_result`].
+#[must_use = "a fallible call chain does nothing with its error unless ended with `into_result`"]
+pub struct TryCallChainMut<'a, S: ?Sized, R, E> {
+	pub(crate) this: &'a mut S,
+	pub(crate) step: usize,
+	pub(crate) result: Result<R, ChainError<E>>
+}
+
+impl<'a, S: ?Sized, R, E> TryCallChainMut<'a, S, R, E> {
+	#[inline]
+	pub(crate) fn new<T: ChainTry<Output = R, Error = E>>(this: &'a mut S, result: T) -> Self {
+		TryCallChainMut {
+			this,
+			step: 0,
+			result: result.into_chain_step().map_err(|error| ChainError { step: 0, error })
+		}
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject if no previous step has failed, continuing the fallible call chain immutably.
+	pub fn try_chain<T: ChainTry<Error = E>, F: FnOnce(&S) -> T>(self, f: F) -> TryCallChainMut<'a, S, T::Output, E> {
+		let step = self.step + 1;
+		TryCallChainMut {
+			result: match self.result {
+				Ok(_) => f(self.this).into_chain_step().map_err(|error| ChainError { step, error }),
+				Err(err) => Err(err)
+			},
+			this: self.this,
+			step
+		}
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject if no previous step has failed, continuing the fallible call chain mutably.
+	pub fn try_chain_mut<T: ChainTry<Error = E>, F: FnOnce(&mut S) -> T>(self, f: F) -> TryCallChainMut<'a, S, T::Output, E> {
+		let step = self.step + 1;
+		TryCallChainMut {
+			result: match self.result {
+				Ok(_) => f(&mut *self.this).into_chain_step().map_err(|error| ChainError { step, error }),
+				Err(err) => Err(err)
+			},
+			this: self.this,
+			step
+		}
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the chained subject or the first failure.
+	pub fn into_result(self) -> Result<&'a mut S, ChainError<E>> {
+		self.result.map(|_| self.this)
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Ends the call chain, returning the result of the last chained function or the first failure.
+	pub fn into_chain_result(self) -> Result<crate::CallChainResultMut<'a, S, R>, ChainError<E>> {
+		let this = self.this;
+		self.result.map(|result| crate::CallChainResultMut { this, result })
+	}
+}
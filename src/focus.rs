@@ -0,0 +1,122 @@
+use core::marker::PhantomData;
+
+/// A call chain focused on a part of its parent subject, such as a field. Created by [`CallChainMut::focus_mut`](crate::CallChainMut::focus_mut).
+///
+/// The parent subject stays mutably borrowed by the focused call chain until it is ended with [`FocusCallChain::back`].
+///
+/// ```compile_fail
+/// use chainer::*;
+///
+/// struct Point { x: i32, y: i32 }
+///
+/// fn main() {
+///     let mut point = Point { x: 0, y: 0 };
+///     let focused = point.focus_mut(|point| &mut point.x);
+///     point.y = 1; // the parent can't be used while it is focused
+///     focused.chain_mut(|x| *x = 1);
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// struct Tls { enabled: bool, port: u16 }
+/// impl Tls {
+///     fn enable(&mut self) {
+///         self.enabled = true;
+///     }
+/// }
+///
+/// struct Config { tls: Tls, name: &'static str }
+/// impl Config {
+///     fn rename(&mut self) {
+///         self.name = "secure";
+///     }
+/// }
+///
+/// fn main() {
+///     let mut config = Config { tls: Tls { enabled: false, port: 0 }, name: "" };
+///
+///     config
+///         .focus_mut(|config| &mut config.tls)
+///         .chain_mut(Tls::enable)
+///         .chain_mut(|tls| tls.port = 443)
+///         .back()
+///         .chain_mut(Config::rename);
+///
+///     assert!(config.tls.enabled);
+///     assert_eq!(config.tls.port, 443);
+///     assert_eq!(config.name, "secure");
+/// }
+/// ```
+pub struct FocusCallChain<'a, P: ?Sized, T: ?Sized, G, R = ()> {
+	parent: &'a mut P,
+	focus: G,
+	#[cfg_attr(not(feature = "results"), allow(dead_code))]
+	result: R,
+	_focused: PhantomData<fn(&mut P) -> &mut T>
+}
+
+impl<'a, P: ?Sized, T: ?Sized, G: Fn(&mut P) -> &mut T, R> FocusCallChain<'a, P, T, G, R> {
+	#[inline]
+	pub(crate) fn new(parent: &'a mut P, focus: G, result: R) -> Self {
+		FocusCallChain { parent, focus, result, _focused: PhantomData }
+	}
+
+	#[inline]
+	/// Calls `f` with the focused subject, continuing the call chain immutably.
+	pub fn chain<U, F: FnOnce(&T) -> U>(self, f: F) -> Self {
+		f((self.focus)(self.parent));
+		self
+	}
+
+	#[inline]
+	/// Calls `f` with the focused subject, continuing the call chain mutably.
+	pub fn chain_mut<U, F: FnOnce(&mut T) -> U>(self, f: F) -> Self {
+		f((self.focus)(self.parent));
+		self
+	}
+
+	#[inline]
+	/// Ends the focused call chain, returning the parent subject so that the call chain can continue on it.
+	pub fn back(self) -> &'a mut P {
+		self.parent
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Calls `f` with the focused subject, continuing the call chain immutably with access to its result.
+	pub fn chain_result<U, F: FnOnce(&T) -> U>(self, f: F) -> FocusCallChain<'a, P, T, G, U> {
+		let FocusCallChain { parent, focus, .. } = self;
+		let result = f(focus(parent));
+		FocusCallChain::new(parent, focus, result)
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Calls `f` with the focused subject, continuing the call chain mutably with access to its result.
+	pub fn chain_mut_result<U, F: FnOnce(&mut T) -> U>(self, f: F) -> FocusCallChain<'a, P, T, G, U> {
+		let FocusCallChain { parent, focus, .. } = self;
+		let result = f(focus(parent));
+		FocusCallChain::new(parent, focus, result)
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Returns the result of the last chained function.
+	pub fn result(&self) -> &R {
+		&self.result
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Ends the focused call chain, returning to the parent subject with the result of the last chained function.
+	pub fn unfocus(self) -> crate::CallChainResultMut<'a, P, R> {
+		crate::CallChainResultMut {
+			this: self.parent,
+			result: self.result
+		}
+	}
+}
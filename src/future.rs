@@ -0,0 +1,100 @@
+use core::{future::Future, marker::PhantomData, pin::Pin, task::{Context, Poll}};
+
+/// An asynchronous call chain. Created by [`CallChainExt::chain_async`](crate::CallChainExt::chain_async).
+///
+/// Each chained function is awaited in order. Awaiting the chain resolves to the chained subject.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AsyncCallChain<'a, S: ?Sized, Fut> {
+	future: Fut,
+	_subject: PhantomData<&'a S>
+}
+
+impl<'a, S: ?Sized + 'a, R, Fut: Future<Output = (&'a S, R)>> AsyncCallChain<'a, S, Fut> {
+	#[inline]
+	pub(crate) fn new(future: Fut) -> Self {
+		AsyncCallChain { future, _subject: PhantomData }
+	}
+
+	#[inline]
+	/// Awaits `f` with the chained subject once every previous chained function has completed, continuing the call chain immutably.
+	pub fn chain_async<T, F: AsyncFnOnce(&S) -> T>(self, f: F) -> AsyncCallChain<'a, S, impl Future<Output = (&'a S, T)>> {
+		AsyncCallChain::new(async move {
+			let (this, _) = self.future.await;
+			(this, f(this).await)
+		})
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Returns a future resolving to the result of the last chained function.
+	pub async fn into_chain_result(self) -> crate::CallChainResult<'a, S, R> {
+		let (this, result) = self.future.await;
+		crate::CallChainResult { this, result }
+	}
+}
+
+impl<'a, S: ?Sized + 'a, R, Fut: Future<Output = (&'a S, R)>> Future for AsyncCallChain<'a, S, Fut> {
+	type Output = &'a S;
+
+	#[inline]
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<&'a S> {
+		// SAFETY: `future` is structurally pinned; it is never moved out of `self` and `AsyncCallChain` has no `Drop` impl.
+		let future = unsafe { self.map_unchecked_mut(|chain| &mut chain.future) };
+		future.poll(cx).map(|(this, _)| this)
+	}
+}
+
+/// An asynchronous mutable call chain. Created by [`CallChainMut::chain_mut_async`](crate::CallChainMut::chain_mut_async).
+///
+/// Each chained function is awaited in order. Awaiting the chain resolves to the chained subject.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AsyncCallChainMut<'a, S: ?Sized, Fut> {
+	future: Fut,
+	_subject: PhantomData<&'a mut S>
+}
+
+impl<'a, S: ?Sized + 'a, R, Fut: Future<Output = (&'a mut S, R)>> AsyncCallChainMut<'a, S, Fut> {
+	#[inline]
+	pub(crate) fn new(future: Fut) -> Self {
+		AsyncCallChainMut { future, _subject: PhantomData }
+	}
+
+	#[inline]
+	/// Awaits `f` with the chained subject once every previous chained function has completed, continuing the call chain immutably.
+	pub fn chain_async<T, F: AsyncFnOnce(&S) -> T>(self, f: F) -> AsyncCallChainMut<'a, S, impl Future<Output = (&'a mut S, T)>> {
+		AsyncCallChainMut::new(async move {
+			let (this, _) = self.future.await;
+			let result = f(this).await;
+			(this, result)
+		})
+	}
+
+	#[inline]
+	/// Awaits `f` with the chained subject once every previous chained function has completed, continuing the call chain mutably.
+	pub fn chain_mut_async<T, F: AsyncFnOnce(&mut S) -> T>(self, f: F) -> AsyncCallChainMut<'a, S, impl Future<Output = (&'a mut S, T)>> {
+		AsyncCallChainMut::new(async move {
+			let (this, _) = self.future.await;
+			let result = f(&mut *this).await;
+			(this, result)
+		})
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Returns a future resolving to the result of the last chained function.
+	pub async fn into_chain_result(self) -> crate::CallChainResultMut<'a, S, R> {
+		let (this, result) = self.future.await;
+		crate::CallChainResultMut { this, result }
+	}
+}
+
+impl<'a, S: ?Sized + 'a, R, Fut: Future<Output = (&'a mut S, R)>> Future for AsyncCallChainMut<'a, S, Fut> {
+	type Output = &'a mut S;
+
+	#[inline]
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<&'a mut S> {
+		// SAFETY: `future` is structurally pinned; it is never moved out of `self` and `AsyncCallChainMut` has no `Drop` impl.
+		let future = unsafe { self.map_unchecked_mut(|chain| &mut chain.future) };
+		future.poll(cx).map(|(this, _)| this)
+	}
+}
@@ -0,0 +1,261 @@
+use core::{cell::{Ref, RefCell, RefMut}, ops::{Deref, DerefMut}};
+
+/// A call chain through a guard, such as the one returned by [`RefCell::borrow_mut`] or `Mutex::lock`.
+///
+/// The guard is taken once and held for the whole call chain, and is released when the call chain is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+/// use core::cell::RefCell;
+///
+/// struct Counter { value: i32 }
+/// impl Counter {
+///     fn increment(&mut self) {
+///         self.value += 1;
+///     }
+///     fn print(&self) {
+///         println!("{}", self.value);
+///     }
+/// }
+///
+/// fn main() {
+///     let counter = RefCell::new(Counter { value: 0 });
+///
+///     counter
+///         .chain_borrow_mut()
+///         .chain_mut(Counter::increment)
+///         .chain_mut(Counter::increment)
+///         .chain(Counter::print);
+///
+///     assert_eq!(counter.borrow().value, 2);
+///
+///     // 2
+/// }
+/// ```
+pub struct GuardCallChain<G> {
+	guard: G
+}
+
+impl<G: Deref> GuardCallChain<G> {
+	#[inline]
+	/// Starts a call chain through `guard`.
+	pub fn new(guard: G) -> Self {
+		GuardCallChain { guard }
+	}
+
+	#[inline]
+	/// Calls `f` with the guarded value, continuing the call chain immutably.
+	pub fn chain<R, F: FnOnce(&G::Target) -> R>(self, f: F) -> Self {
+		f(&self.guard);
+		self
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the guard without releasing it.
+	pub fn into_guard(self) -> G {
+		self.guard
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Calls `f` with the guarded value, continuing the call chain immutably with access to its result.
+	pub fn chain_result<R, F: FnOnce(&G::Target) -> R>(self, f: F) -> GuardCallChainResult<G, R> {
+		GuardCallChainResult {
+			result: f(&self.guard),
+			guard: self.guard
+		}
+	}
+}
+
+impl<G: DerefMut> GuardCallChain<G> {
+	#[inline]
+	/// Calls `f` with the guarded value, continuing the call chain mutably.
+	pub fn chain_mut<R, F: FnOnce(&mut G::Target) -> R>(mut self, f: F) -> Self {
+		f(&mut self.guard);
+		self
+	}
+
+	#[cfg(feature = "results")]
+	#[inline]
+	/// Calls `f` with the guarded value, continuing the call chain mutably with access to its result.
+	pub fn chain_mut_result<R, F: FnOnce(&mut G::Target) -> R>(mut self, f: F) -> GuardCallChainResult<G, R> {
+		GuardCallChainResult {
+			result: f(&mut self.guard),
+			guard: self.guard
+		}
+	}
+}
+
+/// A result from a call chain through a guard. Dereferences to the return value but can also be used to chain further.
+///
+/// The guard is released when the result is dropped, or when it is ended with [`GuardCallChainResult::into_result`].
+#[cfg(feature = "results")]
+pub struct GuardCallChainResult<G, R> {
+	guard: G,
+
+	/// The result of the chained function.
+	pub result: R
+}
+
+#[cfg(feature = "results")]
+impl<G, R> GuardCallChainResult<G, R> {
+	#[inline]
+	/// Releases the guard and returns the result of the chained function.
+	pub fn into_result(self) -> R {
+		self.result
+	}
+
+	#[inline]
+	/// Returns the guard, without releasing it, and the result of the chained function.
+	pub fn into_parts(self) -> (G, R) {
+		(self.guard, self.result)
+	}
+}
+
+#[cfg(feature = "results")]
+impl<G: Deref, T> GuardCallChainResult<G, T> {
+	#[inline]
+	/// Calls `f` with the guarded value, continuing the call chain immutably.
+	pub fn chain_result<R, F: FnOnce(&G::Target) -> R>(self, f: F) -> GuardCallChainResult<G, R> {
+		GuardCallChainResult {
+			result: f(&self.guard),
+			guard: self.guard
+		}
+	}
+}
+
+#[cfg(feature = "results")]
+impl<G: DerefMut, T> GuardCallChainResult<G, T> {
+	#[inline]
+	/// Calls `f` with the guarded value, continuing the call chain mutably.
+	pub fn chain_mut_result<R, F: FnOnce(&mut G::Target) -> R>(mut self, f: F) -> GuardCallChainResult<G, R> {
+		GuardCallChainResult {
+			result: f(&mut self.guard),
+			guard: self.guard
+		}
+	}
+}
+
+#[cfg(feature = "results")]
+impl<G, R> Deref for GuardCallChainResult<G, R> {
+	type Target = R;
+
+	#[inline]
+	fn deref(&self) -> &R {
+		&self.result
+	}
+}
+#[cfg(feature = "results")]
+impl<G, R> DerefMut for GuardCallChainResult<G, R> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut R {
+		&mut self.result
+	}
+}
+
+/// Enables call chaining through the borrow guards of [`RefCell`].
+pub trait CallChainRefCell<T: ?Sized> {
+	/// Immutably borrows the value and starts a call chain on it. The borrow is released when the call chain is dropped.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is currently mutably borrowed, as [`RefCell::borrow`] does.
+	fn chain_borrow(&self) -> GuardCallChain<Ref<'_, T>>;
+
+	/// Mutably borrows the value and starts a call chain on it. The borrow is released when the call chain is dropped.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is currently borrowed, as [`RefCell::borrow_mut`] does.
+	fn chain_borrow_mut(&self) -> GuardCallChain<RefMut<'_, T>>;
+}
+impl<T: ?Sized> CallChainRefCell<T> for RefCell<T> {
+	#[inline]
+	fn chain_borrow(&self) -> GuardCallChain<Ref<'_, T>> {
+		GuardCallChain::new(self.borrow())
+	}
+
+	#[inline]
+	fn chain_borrow_mut(&self) -> GuardCallChain<RefMut<'_, T>> {
+		GuardCallChain::new(self.borrow_mut())
+	}
+}
+
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// Enables call chaining through the guard of [`Mutex`].
+#[cfg(feature = "std")]
+pub trait CallChainMutex<T: ?Sized> {
+	/// Locks the mutex and starts a call chain on its value. The lock is released when the call chain is dropped.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the mutex is poisoned, as [`Mutex::lock`] does.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	/// use std::sync::Mutex;
+	///
+	/// struct Counter { value: i32 }
+	/// impl Counter {
+	///     fn increment(&mut self) {
+	///         self.value += 1;
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let counter = Mutex::new(Counter { value: 0 });
+	///
+	///     counter
+	///         .chain_lock()
+	///         .unwrap()
+	///         .chain_mut(Counter::increment)
+	///         .chain_mut(Counter::increment);
+	///
+	///     assert_eq!(counter.lock().unwrap().value, 2);
+	/// }
+	/// ```
+	fn chain_lock(&self) -> Result<GuardCallChain<MutexGuard<'_, T>>, PoisonError<MutexGuard<'_, T>>>;
+}
+#[cfg(feature = "std")]
+impl<T: ?Sized> CallChainMutex<T> for Mutex<T> {
+	#[inline]
+	fn chain_lock(&self) -> Result<GuardCallChain<MutexGuard<'_, T>>, PoisonError<MutexGuard<'_, T>>> {
+		self.lock().map(GuardCallChain::new)
+	}
+}
+
+/// Enables call chaining through the guards of [`RwLock`].
+#[cfg(feature = "std")]
+pub trait CallChainRwLock<T: ?Sized> {
+	/// Locks the value for reading and starts a call chain on it. The lock is released when the call chain is dropped.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the lock is poisoned, as [`RwLock::read`] does.
+	fn chain_read(&self) -> Result<GuardCallChain<RwLockReadGuard<'_, T>>, PoisonError<RwLockReadGuard<'_, T>>>;
+
+	/// Locks the value for writing and starts a call chain on it. The lock is released when the call chain is dropped.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the lock is poisoned, as [`RwLock::write`] does.
+	fn chain_write(&self) -> Result<GuardCallChain<RwLockWriteGuard<'_, T>>, PoisonError<RwLockWriteGuard<'_, T>>>;
+}
+#[cfg(feature = "std")]
+impl<T: ?Sized> CallChainRwLock<T> for RwLock<T> {
+	#[inline]
+	fn chain_read(&self) -> Result<GuardCallChain<RwLockReadGuard<'_, T>>, PoisonError<RwLockReadGuard<'_, T>>> {
+		self.read().map(GuardCallChain::new)
+	}
+
+	#[inline]
+	fn chain_write(&self) -> Result<GuardCallChain<RwLockWriteGuard<'_, T>>, PoisonError<RwLockWriteGuard<'_, T>>> {
+		self.write().map(GuardCallChain::new)
+	}
+}
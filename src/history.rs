@@ -0,0 +1,188 @@
+use crate::{CallChainResult, CallChainResultMut};
+
+/// The results of every chained function, as built up by [`chain_keep`](crate::ResultChain::chain_keep).
+///
+/// Wraps a tuple of the results, in call order, so that a history is never mistaken for a tuple returned by a chained function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Kept<H>(pub H);
+
+/// A history of chained function results which can be appended to.
+///
+/// Implemented for [`Kept`] tuples of up to 11 elements, so that a history can hold up to 12 results.
+pub trait History {
+	/// The history with `T` appended to the end.
+	type Push<T>;
+
+	/// Appends `value` to the end of the history.
+	fn push<T>(self, value: T) -> Self::Push<T>;
+}
+
+macro_rules! impl_history {
+	($($name:ident)*) => {
+		impl<$($name,)*> History for Kept<($($name,)*)> {
+			type Push<T> = Kept<($($name,)* T,)>;
+
+			#[inline]
+			#[allow(non_snake_case)]
+			fn push<T>(self, value: T) -> Self::Push<T> {
+				let Kept(($($name,)*)) = self;
+				Kept(($($name,)* value,))
+			}
+		}
+	};
+}
+
+impl_history!();
+impl_history!(A);
+impl_history!(A B);
+impl_history!(A B C);
+impl_history!(A B C D);
+impl_history!(A B C D E);
+impl_history!(A B C D E F);
+impl_history!(A B C D E F G);
+impl_history!(A B C D E F G H);
+impl_history!(A B C D E F G H I);
+impl_history!(A B C D E F G H I J);
+impl_history!(A B C D E F G H I J K);
+
+impl<'a, S: ?Sized, H> CallChainResult<'a, S, Kept<H>>
+where
+	Kept<H>: History
+{
+	#[inline]
+	/// Calls `f` with the chained subject, continuing the call chain immutably and appending its result to the history.
+	pub fn chain_keep<R, F: FnOnce(&S) -> R>(self, f: F) -> CallChainResult<'a, S, <Kept<H> as History>::Push<R>> {
+		CallChainResult {
+			result: self.result.push(f(self.this)),
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Returns the results of every chained function, in call order.
+	pub fn results(&self) -> &H {
+		&self.result.0
+	}
+
+	#[inline]
+	/// Returns the results of every chained function, in call order.
+	pub fn into_results(self) -> H {
+		self.result.0
+	}
+}
+
+impl<'a, S: ?Sized, H> CallChainResultMut<'a, S, Kept<H>>
+where
+	Kept<H>: History
+{
+	#[inline]
+	/// Calls `f` with the chained subject, continuing the call chain immutably and appending its result to the history.
+	pub fn chain_keep<R, F: FnOnce(&S) -> R>(self, f: F) -> CallChainResultMut<'a, S, <Kept<H> as History>::Push<R>> {
+		let result = f(self.this);
+		CallChainResultMut {
+			result: self.result.push(result),
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject, continuing the call chain mutably and appending its result to the history.
+	pub fn chain_mut_keep<R, F: FnOnce(&mut S) -> R>(self, f: F) -> CallChainResultMut<'a, S, <Kept<H> as History>::Push<R>> {
+		let result = f(&mut *self.this);
+		CallChainResultMut {
+			result: self.result.push(result),
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Returns the results of every chained function, in call order.
+	pub fn results(&self) -> &H {
+		&self.result.0
+	}
+
+	#[inline]
+	/// Returns the results of every chained function, in call order.
+	pub fn into_results(self) -> H {
+		self.result.0
+	}
+}
+
+/// Starts keeping the history of a call chain from its current result.
+///
+/// Implemented for call chains whose result isn't a [`Kept`] history yet, which have their own `chain_keep` methods.
+pub trait KeepCallChain {
+	/// The chained subject.
+	type Subject: ?Sized;
+
+	/// The call chain continued with a history.
+	type Kept<H>;
+
+	/// Calls `f` with the chained subject, continuing the call chain immutably with a history of the current result and the result of `f`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Point { x: i32, y: i32 }
+	///
+	/// fn main() {
+	///     let point = Point { x: 1, y: 2 };
+	///
+	///     let results = point
+	///         .chain_result(|point| (point.x, point.y))
+	///         .chain_keep(|point| point.x + point.y)
+	///         .into_results();
+	///
+	///     assert_eq!(results, ((1, 2), 3));
+	/// }
+	/// ```
+	fn chain_keep<R, F: FnOnce(&Self::Subject) -> R>(self, f: F) -> Self::Kept<R>;
+}
+
+impl<'a, S: ?Sized, T> KeepCallChain for CallChainResult<'a, S, T> {
+	type Subject = S;
+	type Kept<R> = CallChainResult<'a, S, Kept<(T, R)>>;
+
+	#[inline]
+	fn chain_keep<R, F: FnOnce(&S) -> R>(self, f: F) -> Self::Kept<R> {
+		CallChainResult {
+			result: Kept((self.result, f(self.this))),
+			this: self.this
+		}
+	}
+}
+
+impl<'a, S: ?Sized, T> KeepCallChain for CallChainResultMut<'a, S, T> {
+	type Subject = S;
+	type Kept<R> = CallChainResultMut<'a, S, Kept<(T, R)>>;
+
+	#[inline]
+	fn chain_keep<R, F: FnOnce(&S) -> R>(self, f: F) -> Self::Kept<R> {
+		let result = f(self.this);
+		CallChainResultMut {
+			result: Kept((self.result, result)),
+			this: self.this
+		}
+	}
+}
+
+/// Starts keeping the history of a mutable call chain from its current result.
+///
+/// Implemented for mutable call chains whose result isn't a [`Kept`] history yet, which have their own `chain_mut_keep` methods.
+pub trait KeepCallChainMut: KeepCallChain {
+	/// Calls `f` with the chained subject, continuing the call chain mutably with a history of the current result and the result of `f`.
+	fn chain_mut_keep<R, F: FnOnce(&mut Self::Subject) -> R>(self, f: F) -> Self::Kept<R>;
+}
+
+impl<'a, S: ?Sized, T> KeepCallChainMut for CallChainResultMut<'a, S, T> {
+	#[inline]
+	fn chain_mut_keep<R, F: FnOnce(&mut S) -> R>(self, f: F) -> Self::Kept<R> {
+		let result = f(&mut *self.this);
+		CallChainResultMut {
+			result: Kept((self.result, result)),
+			this: self.this
+		}
+	}
+}
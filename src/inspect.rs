@@ -0,0 +1,6 @@
+use core::{fmt::Debug, panic::Location};
+
+/// Prints `value` to stderr along with the location of the chained call, like [`dbg!`](std::dbg).
+pub(crate) fn dbg<T: Debug + ?Sized>(location: &Location<'_>, value: &T) {
+	std::eprintln!("[{}:{}:{}] {:#?}", location.file(), location.line(), location.column(), value);
+}
@@ -1,21 +1,35 @@
 #![no_std]
 #![deny(missing_docs)]
-#![cfg_attr(feature = "results", feature(min_specialization))]
 
 //! A cursed crate that allows for global call chaining with access to chained function results
 //!
-//! **Currently the `results` feature requires the `min_specialization` Rust Nightly feature.**
-//!
 //! # Usage
 //!
+//! Requires Rust 1.85 or newer, as `chain_async` takes `AsyncFnOnce` closures.
+//!
 //! ```toml
 //! [dependencies]
 //! chainer = "*"
 //!
-//! ## or, with a nightly compiler
+//! ## or, with access to chained function results
 //!
 //! [dependencies]
 //! chainer = { version = "*", features = ["results"] }
+//!
+//! ## or, with the `#[chainable]` attribute macro
+//!
+//! [dependencies]
+//! chainer = { version = "*", features = ["macros"] }
+//!
+//! ## or, with `Mutex` and `RwLock` guards, panic catching and `chain_dbg`
+//!
+//! [dependencies]
+//! chainer = { version = "*", features = ["std"] }
+//!
+//! ## or, only on types which opt in with `Chainable`
+//!
+//! [dependencies]
+//! chainer = { version = "*", default-features = false }
 //! ```
 //!
 //! # Examples
@@ -71,11 +85,20 @@
 //! }
 //! ```
 //!
+//! ## Iterators
+//!
+//! `CallChain::chain` shares its name with `Iterator::chain`. In iterator-heavy code, import `chainer::prelude::*` instead of `chainer::*`, which leaves out `CallChain` and provides `chain_shared` in its place.
+//!
+//! Alternatively, disable the default `blanket` feature so that only types implementing `Chainable` can be chained, e.g. with `#[derive(Chainable)]` and the `macros` feature.
+//!
 //! ## `features = ["results"]`
 //!
+//! The `results` feature is additive: `chain` and `chain_mut` keep returning the subject, and `chain_result` and `chain_mut_result` are added alongside them. Read-only `chain_result` steps keep a mutable call chain mutable, so they can be mixed freely with `chain_mut_result` steps.
+//!
 //! ### Immutable call chaining
 //!
-//! ```ignore
+#![cfg_attr(feature = "results", doc = "```rust")]
+#![cfg_attr(not(feature = "results"), doc = "```ignore")]
 //! use chainer::*;
 //!
 //! struct HelloWorld;
@@ -88,9 +111,9 @@
 //!
 //! fn main() {
 //!     let value: &'static str = HelloWorld
-//!         .chain(HelloWorld::print)
-//!         .chain(HelloWorld::print)
-//!         .chain(HelloWorld::print)
+//!         .chain_result(HelloWorld::print)
+//!         .chain_result(HelloWorld::print)
+//!         .chain_result(HelloWorld::print)
 //!         .result;
 //!
 //!     // Hello, world!
@@ -102,7 +125,8 @@
 //!
 //! ### Mutable call chaining
 //!
-//! ```ignore
+#![cfg_attr(feature = "results", doc = "```rust")]
+#![cfg_attr(not(feature = "results"), doc = "```ignore")]
 //! use chainer::*;
 //!
 //! struct Counter { value: i32 }
@@ -116,9 +140,9 @@
 //!
 //! fn main() {
 //!     let value: i32 = Counter { value: 0 }
-//!         .chain_mut(Counter::increment)
-//!         .chain_mut(Counter::increment)
-//!         .chain_mut(Counter::increment)
+//!         .chain_mut_result(Counter::increment)
+//!         .chain_mut_result(Counter::increment)
+//!         .chain_mut_result(Counter::increment)
 //!         .result;
 //!
 //!        println!("{value}");
@@ -130,17 +154,145 @@
 //! }
 //! ```
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod macros;
+
+mod basic;
+pub use basic::{CallChain, CallChainExt, CallChainMut};
+
+#[cfg(feature = "std")]
+mod catch;
+#[cfg(feature = "std")]
+pub use catch::*;
+
+mod fallible;
+pub use fallible::*;
+
+mod focus;
+pub use focus::*;
+
+mod future;
+pub use future::*;
+
+mod guard;
+pub use guard::*;
+
+#[cfg(feature = "std")]
+mod inspect;
+
+mod observe;
+pub use observe::*;
+
+mod opt_in;
+pub use opt_in::*;
+
+mod owned;
+pub use owned::*;
+
+mod pipe;
+pub use pipe::*;
+
+mod repeat;
+
+#[cfg(feature = "results")]
+pub use repeat::Repetition;
+
+mod transaction;
+pub use transaction::Snapshot;
+
 #[cfg(feature = "results")]
 mod results;
 
 #[cfg(feature = "results")]
 pub use results::*;
 
-#[cfg(not(feature = "results"))]
-mod basic;
+#[cfg(feature = "results")]
+mod combinators;
+
+#[cfg(feature = "results")]
+mod rechain;
+
+#[cfg(feature = "results")]
+pub use rechain::*;
+
+#[cfg(feature = "results")]
+mod history;
+
+#[cfg(feature = "results")]
+pub use history::*;
+
+/// For each `&self` and `&mut self` method of the `impl` block, a trait named `{Type}Chain` is generated with a `chain_{method}` method, which calls the method through [`CallChain::chain`] or [`CallChainMut::chain_mut`].
+///
+/// With the `results` feature, a trait named `{Type}ChainResult` is also generated with a `chain_{method}_result` method, which calls the method through [`ResultChain::chain_result`] or [`ResultChainMut::chain_mut_result`] so that its return value is kept. It is implemented for `&mut Type` and for [`CallChainResultMut`]s of the type, so read-only and mutating steps can be mixed freely. Its read-only methods are also generated in a trait named `{Type}ChainResultShared`, implemented for `&Type` and for [`CallChainResult`]s of the type.
+///
+/// Methods returning a reference into the subject are chained through [`ResultChain::chain_ref`] or [`ResultChainMut::chain_mut_ref`]. Methods whose result may borrow from the subject in any other way, e.g. `Option<&T>`, `Iter<T>` or `impl Trait`, don't get a `chain_{method}_result` method. Lifetimes hidden in types without generic arguments, such as `Chars`, can't be detected, so they must be written out, e.g. `Chars<'_>`.
+///
+/// The generated methods are prefixed with `chain_` as inherent methods always take precedence over trait methods of the same name.
+///
+/// Without the default `blanket` feature, the type must implement [`Chainable`].
+///
+/// The generated traits are private by default. A visibility can be given to the attribute, e.g. `#[chainable(pub)]`.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// struct Counter { value: i32 }
+///
+/// #[chainable]
+/// impl Counter {
+///     fn increment(&mut self) -> i32 {
+///         self.value += 1;
+///         self.value
+///     }
+///     fn add(&mut self, amount: i32) -> i32 {
+///         self.value += amount;
+///         self.value
+///     }
+/// }
+///
+/// fn main() {
+///     let mut counter = Counter { value: 0 };
+///
+///     counter
+///         .chain_increment()
+///         .chain_add(2)
+///         .chain_increment();
+///
+///     assert_eq!(counter.value, 4);
+/// }
+/// ```
+#[cfg(feature = "macros")]
+pub use chainer_macros::chainable;
+
+/// Implements [`Chainable`] for a type, opting it in to call chaining when the default `blanket` feature is disabled.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// #[derive(Chainable)]
+/// struct Counter { value: i32 }
+///
+/// fn main() {
+///     let mut counter = Counter { value: 0 };
+///
+///     counter.chain_mut(|counter| counter.value += 1);
+///
+///     assert_eq!(counter.value, 1);
+/// }
+/// ```
+#[cfg(feature = "macros")]
+pub use chainer_macros::Chainable;
+
+pub mod prelude;
 
-#[cfg(not(feature = "results"))]
-pub use basic::*;
+#[cfg(all(test, feature = "macros"))]
+extern crate self as chainer;
 
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -0,0 +1,116 @@
+/// Chains method calls which take extra arguments.
+///
+/// Each step is a method name, optionally followed by its arguments. Steps are called with [`CallChainMut::chain_mut`](crate::CallChainMut::chain_mut), so the subject must be mutable. To chain on an immutable subject, pass a reference to it, e.g. `chain!(&subject => ...)`.
+///
+/// Steps prefixed with `&` only have immutable access to the subject, so calling a `&mut self` method in one fails to compile.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// struct Builder { name: &'static str, total: i32 }
+/// impl Builder {
+///     fn set_name(&mut self, name: &'static str) {
+///         self.name = name;
+///     }
+///     fn add(&mut self, a: i32, b: i32) {
+///         self.total += a + b;
+///     }
+///     fn finish(&self) {
+///         println!("{}: {}", self.name, self.total);
+///     }
+/// }
+///
+/// fn main() {
+///     let mut builder = Builder { name: "", total: 0 };
+///
+///     chain!(builder => set_name("x"), add(1, 2), &finish);
+///
+///     assert_eq!(builder.name, "x");
+///     assert_eq!(builder.total, 3);
+///
+///     // x: 3
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use chainer::*;
+///
+/// struct Counter { value: i32 }
+/// impl Counter {
+///     fn increment(&mut self) {
+///         self.value += 1;
+///     }
+/// }
+///
+/// fn main() {
+///     let mut counter = Counter { value: 0 };
+///
+///     // error[E0596]: cannot borrow `*this` as mutable, as it is behind a `&` reference
+///     chain!(counter => &increment);
+/// }
+/// ```
+#[macro_export]
+macro_rules! chain {
+	(@call discard $call:expr) => {
+		{
+			let _ = $call;
+		}
+	};
+
+	(@call keep $call:expr) => {
+		$call
+	};
+
+	(@steps $chain_mut:ident $mode:ident ($($acc:tt)*)) => {
+		$($acc)*
+	};
+
+	(@steps $chain_mut:ident $mode:ident ($($acc:tt)*) & $method:ident $(($($arg:expr),* $(,)?))? $(, $($rest:tt)*)?) => {
+		$crate::chain!(@steps $chain_mut $mode ($($acc)*.$chain_mut(|this| { let this = &*this; $crate::chain!(@call $mode this.$method($($($arg),*)?)) })) $($($rest)*)?)
+	};
+
+	(@steps $chain_mut:ident $mode:ident ($($acc:tt)*) $method:ident $(($($arg:expr),* $(,)?))? $(, $($rest:tt)*)?) => {
+		$crate::chain!(@steps $chain_mut $mode ($($acc)*.$chain_mut(|this| $crate::chain!(@call $mode this.$method($($($arg),*)?)))) $($($rest)*)?)
+	};
+
+	($subject:expr => $($steps:tt)*) => {
+		$crate::chain!(@steps chain_mut discard (($subject)) $($steps)*)
+	};
+}
+
+/// Chains method calls which take extra arguments, with access to chained function results.
+///
+/// Each step is a method name, optionally followed by its arguments. Steps are called with [`ResultChainMut::chain_mut_result`](crate::ResultChainMut::chain_mut_result), so the subject must be mutable. To chain on an immutable subject, pass a reference to it, e.g. `chain_result!(&subject => ...)`.
+///
+/// Steps prefixed with `&` only have immutable access to the subject, so calling a `&mut self` method in one fails to compile.
+///
+/// The result of every step is kept, so methods returning a reference into the subject can't be called. Use [`ResultChain::chain_ref`](crate::ResultChain::chain_ref) for those instead.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// struct Counter { value: i32 }
+/// impl Counter {
+///     fn add(&mut self, amount: i32) -> i32 {
+///         self.value += amount;
+///         self.value
+///     }
+/// }
+///
+/// fn main() {
+///     let value: i32 = chain_result!(Counter { value: 0 } => add(1), add(2), add(3)).result;
+///
+///     assert_eq!(value, 6);
+/// }
+/// ```
+#[cfg(feature = "results")]
+#[macro_export]
+macro_rules! chain_result {
+	($subject:expr => $($steps:tt)*) => {
+		$crate::chain!(@steps chain_mut_result keep (($subject)) $($steps)*)
+	};
+}
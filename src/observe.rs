@@ -0,0 +1,150 @@
+/// Receives a callback before and after every step of an observed call chain. Attached with [`CallChainExt::observe`](crate::CallChainExt::observe) or [`CallChainMut::observe_mut`](crate::CallChainMut::observe_mut).
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// #[derive(Default)]
+/// struct Trace { steps: Vec<(usize, bool)> }
+/// impl ChainObserver for Trace {
+///     fn after_step(&mut self, step: usize, _name: &'static str, panicked: bool) {
+///         self.steps.push((step, panicked));
+///     }
+/// }
+///
+/// struct Counter { value: i32 }
+/// impl Counter {
+///     fn increment(&mut self) {
+///         self.value += 1;
+///     }
+/// }
+///
+/// fn main() {
+///     let mut counter = Counter { value: 0 };
+///
+///     let trace = counter
+///         .observe_mut(Trace::default())
+///         .chain_mut(Counter::increment)
+///         .chain_mut(Counter::increment)
+///         .into_observer();
+///
+///     assert_eq!(counter.value, 2);
+///     assert_eq!(trace.steps, [(0, false), (1, false)]);
+/// }
+/// ```
+pub trait ChainObserver {
+	/// Called before a step runs, with its zero-based index and the [type name](core::any::type_name) of its function.
+	#[inline]
+	fn before_step(&mut self, step: usize, name: &'static str) {
+		let _ = (step, name);
+	}
+
+	/// Called after a step has run, or while unwinding if it panicked.
+	#[inline]
+	fn after_step(&mut self, step: usize, name: &'static str, panicked: bool) {
+		let _ = (step, name, panicked);
+	}
+}
+
+impl<O: ChainObserver + ?Sized> ChainObserver for &mut O {
+	#[inline]
+	fn before_step(&mut self, step: usize, name: &'static str) {
+		(**self).before_step(step, name)
+	}
+
+	#[inline]
+	fn after_step(&mut self, step: usize, name: &'static str, panicked: bool) {
+		(**self).after_step(step, name, panicked)
+	}
+}
+
+/// Reports the end of a step to the observer, including when the step unwinds.
+struct StepGuard<'o, O: ChainObserver> {
+	observer: &'o mut O,
+	step: usize,
+	name: &'static str,
+	panicked: bool
+}
+
+impl<O: ChainObserver> Drop for StepGuard<'_, O> {
+	#[inline]
+	fn drop(&mut self) {
+		self.observer.after_step(self.step, self.name, self.panicked);
+	}
+}
+
+#[inline]
+fn observe_step<O: ChainObserver, T, R, F: FnOnce(T) -> R>(observer: &mut O, step: usize, this: T, f: F) -> R {
+	let name = core::any::type_name::<F>();
+	observer.before_step(step, name);
+
+	let mut guard = StepGuard { observer, step, name, panicked: true };
+	let result = f(this);
+	guard.panicked = false;
+	result
+}
+
+/// An observed immutable call chain. Created by [`CallChainExt::observe`](crate::CallChainExt::observe).
+pub struct ObservedCallChain<'a, S: ?Sized, O: ChainObserver> {
+	this: &'a S,
+	observer: O,
+	step: usize
+}
+
+impl<'a, S: ?Sized, O: ChainObserver> ObservedCallChain<'a, S, O> {
+	#[inline]
+	pub(crate) fn new(this: &'a S, observer: O) -> Self {
+		ObservedCallChain { observer, step: 0, this }
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject, reporting the step to the observer.
+	pub fn chain<R, F: FnOnce(&S) -> R>(mut self, f: F) -> Self {
+		observe_step(&mut self.observer, self.step, self.this, f);
+		self.step += 1;
+		self
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the observer.
+	pub fn into_observer(self) -> O {
+		self.observer
+	}
+}
+
+/// An observed mutable call chain. Created by [`CallChainMut::observe_mut`](crate::CallChainMut::observe_mut).
+pub struct ObservedCallChainMut<'a, S: ?Sized, O: ChainObserver> {
+	this: &'a mut S,
+	observer: O,
+	step: usize
+}
+
+impl<'a, S: ?Sized, O: ChainObserver> ObservedCallChainMut<'a, S, O> {
+	#[inline]
+	pub(crate) fn new(this: &'a mut S, observer: O) -> Self {
+		ObservedCallChainMut { observer, step: 0, this }
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject, reporting the step to the observer.
+	pub fn chain<R, F: FnOnce(&S) -> R>(mut self, f: F) -> Self {
+		observe_step(&mut self.observer, self.step, &*self.this, f);
+		self.step += 1;
+		self
+	}
+
+	#[inline]
+	/// Calls `f` with the mutable chained subject, reporting the step to the observer.
+	pub fn chain_mut<R, F: FnOnce(&mut S) -> R>(mut self, f: F) -> Self {
+		observe_step(&mut self.observer, self.step, &mut *self.this, f);
+		self.step += 1;
+		self
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the observer.
+	pub fn into_observer(self) -> O {
+		self.observer
+	}
+}
@@ -0,0 +1,44 @@
+/// Opts a type in to call chaining when the default `blanket` feature is disabled.
+///
+/// With the `blanket` feature, every type can be chained and implementing this trait changes nothing. Without it, only types implementing this trait can be chained, so that methods such as [`CallChain::chain`](crate::CallChain::chain) aren't added to every type, e.g. iterators.
+///
+/// With the `macros` feature, it can be implemented with `#[derive(Chainable)]`.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// struct Counter { value: i32 }
+/// impl Chainable for Counter {}
+///
+/// fn main() {
+///     let mut counter = Counter { value: 0 };
+///
+///     counter
+///         .chain_mut(|counter| counter.value += 1)
+///         .chain_mut(|counter| counter.value += 1);
+///
+///     assert_eq!(counter.value, 2);
+/// }
+/// ```
+pub trait Chainable {}
+
+/// Types which can be chained: every type with the `blanket` feature, or only [`Chainable`] types without it.
+///
+/// Implemented automatically, and can't be implemented outside of this crate.
+pub trait Chained: sealed::Sealed {}
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+#[cfg(feature = "blanket")]
+impl<T: ?Sized> sealed::Sealed for T {}
+#[cfg(feature = "blanket")]
+impl<T: ?Sized> Chained for T {}
+
+#[cfg(not(feature = "blanket"))]
+impl<T: ?Sized + Chainable> sealed::Sealed for T {}
+#[cfg(not(feature = "blanket"))]
+impl<T: ?Sized + Chainable> Chained for T {}
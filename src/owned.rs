@@ -0,0 +1,183 @@
+use crate::Chained;
+
+/// Enables by-value call chaining on all sized types.
+///
+/// Useful for values created inline and for consuming builders, as the chained subject is given back by value.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// struct Counter { value: i32 }
+/// impl Counter {
+///     fn increment(&mut self) {
+///         self.value += 1;
+///     }
+///     fn doubled(self) -> Self {
+///         Counter { value: self.value * 2 }
+///     }
+/// }
+///
+/// fn main() {
+///     let counter: Counter = Counter { value: 0 }
+///         .chain_owned(Counter::increment)
+///         .chain_into(Counter::doubled)
+///         .chain_owned(Counter::increment);
+///
+///     assert_eq!(counter.value, 3);
+/// }
+/// ```
+pub trait CallChainOwned: Sized {
+	/// Enables by-value call chaining on all sized types.
+	///
+	/// `f` is called with the chained subject mutably, and the subject is given back by value.
+	#[inline]
+	fn chain_owned<R, F: FnOnce(&mut Self) -> R>(mut self, f: F) -> Self {
+		f(&mut self);
+		self
+	}
+
+	/// Enables by-value call chaining on all sized types.
+	///
+	/// `f` consumes the chained subject and returns the new subject, as consuming builder methods do.
+	#[inline]
+	fn chain_into<F: FnOnce(Self) -> Self>(self, f: F) -> Self {
+		f(self)
+	}
+}
+impl<T: Chained> CallChainOwned for T {}
+
+/// Enables by-value call chaining with access to chained function results on all sized types.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// struct Counter { value: i32 }
+/// impl Counter {
+///     fn increment(&mut self) -> i32 {
+///         self.value += 1;
+///         self.value
+///     }
+/// }
+///
+/// fn main() {
+///     let (counter, value): (Counter, i32) = Counter { value: 0 }
+///         .chain_owned_result(Counter::increment)
+///         .chain_owned_result(Counter::increment)
+///         .into_parts();
+///
+///     assert_eq!(counter.value, 2);
+///     assert_eq!(value, 2);
+/// }
+/// ```
+#[cfg(feature = "results")]
+pub trait ResultChainOwned: Sized {
+	/// Enables by-value call chaining with access to chained function results on all sized types.
+	///
+	/// `f` is called with the chained subject mutably, and the subject is kept by value alongside the result.
+	fn chain_owned_result<R, F: FnOnce(&mut Self) -> R>(self, f: F) -> CallChainResultOwned<Self, R>;
+}
+#[cfg(feature = "results")]
+impl<T: Chained> ResultChainOwned for T {
+	#[inline]
+	fn chain_owned_result<R, F: FnOnce(&mut T) -> R>(mut self, f: F) -> CallChainResultOwned<T, R> {
+		CallChainResultOwned {
+			result: f(&mut self),
+			this: self
+		}
+	}
+}
+
+/// A result from a by-value call chain. Dereferences to the return value but can also be used to chain further, by value.
+#[cfg(feature = "results")]
+pub struct CallChainResultOwned<S, R> {
+	this: S,
+
+	/// The result of the chained function.
+	pub result: R
+}
+
+#[cfg(feature = "results")]
+impl<S, R> CallChainResultOwned<S, R> {
+	#[inline]
+	/// Returns the result of the chained function.
+	pub fn into_result(self) -> R {
+		self.result
+	}
+
+	#[inline]
+	/// Returns the chained subject.
+	pub fn into_inner(self) -> S {
+		self.this
+	}
+
+	#[inline]
+	/// Returns the chained subject and the result of the chained function.
+	pub fn into_parts(self) -> (S, R) {
+		(self.this, self.result)
+	}
+}
+
+#[cfg(feature = "results")]
+impl<S, T> CallChainResultOwned<S, T> {
+	#[inline]
+	/// Calls `f` with the chained subject, continuing the call chain by value.
+	pub fn chain_owned_result<R, F: FnOnce(&mut S) -> R>(mut self, f: F) -> CallChainResultOwned<S, R> {
+		CallChainResultOwned {
+			result: f(&mut self.this),
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Replaces the chained subject with the result of `f`, keeping the result of the previous chained function.
+	pub fn chain_into<F: FnOnce(S) -> S>(self, f: F) -> CallChainResultOwned<S, T> {
+		CallChainResultOwned {
+			this: f(self.this),
+			result: self.result
+		}
+	}
+}
+
+#[cfg(feature = "results")]
+impl<S, R> AsRef<S> for CallChainResultOwned<S, R> {
+	#[inline]
+	fn as_ref(&self) -> &S {
+		&self.this
+	}
+}
+#[cfg(feature = "results")]
+impl<S, R> AsMut<S> for CallChainResultOwned<S, R> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut S {
+		&mut self.this
+	}
+}
+
+#[cfg(feature = "results")]
+impl<S, R> core::ops::Deref for CallChainResultOwned<S, R> {
+	type Target = R;
+
+	#[inline]
+	fn deref(&self) -> &R {
+		&self.result
+	}
+}
+#[cfg(feature = "results")]
+impl<S, R> core::ops::DerefMut for CallChainResultOwned<S, R> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut R {
+		&mut self.result
+	}
+}
+
+#[cfg(feature = "results")]
+impl<S, R> From<CallChainResultOwned<S, R>> for (S, R) {
+	#[inline]
+	fn from(result: CallChainResultOwned<S, R>) -> (S, R) {
+		result.into_parts()
+	}
+}
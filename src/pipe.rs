@@ -0,0 +1,86 @@
+use core::{borrow::Borrow, ops::Deref};
+
+use crate::Chained;
+
+/// Enables piping on all types: the subject is passed into a function, and the function's output is given back.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// fn double(value: i32) -> i32 {
+///     value * 2
+/// }
+///
+/// fn main() {
+///     let value = 5
+///         .pipe(double)
+///         .pipe(|value| value + 1)
+///         .pipe_ref(i32::to_string);
+///
+///     assert_eq!(value, "11");
+/// }
+/// ```
+pub trait Pipe {
+	/// Passes the subject into `f` by value, returning the output of `f`.
+	#[inline]
+	fn pipe<R, F: FnOnce(Self) -> R>(self, f: F) -> R
+	where
+		Self: Sized
+	{
+		f(self)
+	}
+
+	/// Passes the subject into `f` immutably, returning the output of `f`.
+	#[inline]
+	fn pipe_ref<R, F: FnOnce(&Self) -> R>(&self, f: F) -> R {
+		f(self)
+	}
+
+	/// Passes the subject into `f` mutably, returning the output of `f`.
+	#[inline]
+	fn pipe_mut<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+		f(self)
+	}
+
+	/// Passes the subject into `f` through [`Deref`], returning the output of `f`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// fn main() {
+	///     let len = String::from("chainer").pipe_deref(str::len);
+	///
+	///     assert_eq!(len, 7);
+	/// }
+	/// ```
+	#[inline]
+	fn pipe_deref<R, F: FnOnce(&Self::Target) -> R>(&self, f: F) -> R
+	where
+		Self: Deref
+	{
+		f(self)
+	}
+
+	/// Passes the subject into `f` through [`Borrow`], returning the output of `f`.
+	#[inline]
+	fn pipe_borrow<B: ?Sized, R, F: FnOnce(&B) -> R>(&self, f: F) -> R
+	where
+		Self: Borrow<B>
+	{
+		f(self.borrow())
+	}
+
+	/// Passes the subject into `f` through [`AsRef`], returning the output of `f`.
+	#[inline]
+	fn pipe_as_ref<U: ?Sized, R, F: FnOnce(&U) -> R>(&self, f: F) -> R
+	where
+		Self: AsRef<U>
+	{
+		f(self.as_ref())
+	}
+}
+impl<T: ?Sized + Chained> Pipe for T {}
@@ -0,0 +1,56 @@
+//! Everything in this crate except [`CallChain`](crate::CallChain), whose `chain` method collides with [`Iterator::chain`].
+//!
+//! Use [`CallChainShared::chain_shared`] in its place. The rest of the immutable call chaining methods are provided by [`CallChainExt`], which is also exported from the crate root.
+//!
+//! ```rust
+//! use chainer::prelude::*;
+//!
+//! struct Counter { value: i32 }
+//! impl Counter {
+//!     fn increment(&mut self) {
+//!         self.value += 1;
+//!     }
+//!     fn print(&self) {
+//!         println!("{}", self.value);
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let mut counter = Counter { value: 0 };
+//!
+//!     counter
+//!         .chain_mut(Counter::increment)
+//!         .chain_mut(Counter::increment);
+//!
+//!     counter
+//!         .chain_shared(Counter::print)
+//!         .chain_shared(Counter::print);
+//!
+//!     let sum: i32 = [1, 2].iter().chain(&[3]).sum();
+//!     assert_eq!(sum, 6);
+//!
+//!     // 2
+//!     // 2
+//! }
+//! ```
+
+pub use crate::basic::{CallChainExt, CallChainMut, CallChainShared};
+pub use crate::chain;
+pub use crate::fallible::*;
+pub use crate::focus::*;
+pub use crate::future::*;
+pub use crate::guard::*;
+pub use crate::observe::*;
+pub use crate::opt_in::*;
+pub use crate::owned::*;
+pub use crate::pipe::*;
+pub use crate::transaction::Snapshot;
+
+#[cfg(feature = "std")]
+pub use crate::catch::*;
+
+#[cfg(feature = "results")]
+pub use crate::{chain_result, history::*, rechain::*, repeat::Repetition, results::*};
+
+#[cfg(feature = "macros")]
+pub use crate::{chainable, Chainable};
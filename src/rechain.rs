@@ -0,0 +1,123 @@
+use crate::{CallChainResult, CallChainResultMut};
+
+/// A call chain on the result of another call chain. Created by [`CallChainResult::rechain`] and [`CallChainResultMut::rechain`].
+///
+/// The original call chain is kept, and can be returned to with [`Rechain::back`], which hands it the rechained subject as its result.
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// struct Document { text: &'static str }
+/// impl Document {
+///     fn words(&self) -> Vec<&'static str> {
+///         self.text.split(' ').collect()
+///     }
+/// }
+///
+/// fn main() {
+///     let document = Document { text: "b c a" };
+///
+///     let (document, words) = document
+///         .chain_result(Document::words)
+///         .rechain()
+///         .chain_mut(|words| words.sort())
+///         .chain_mut(|words| words.dedup())
+///         .back()
+///         .into_parts();
+///
+///     assert_eq!(document.text, "b c a");
+///     assert_eq!(words, ["a", "b", "c"]);
+/// }
+/// ```
+pub struct Rechain<P, T, R = ()> {
+	parent: P,
+	subject: T,
+	result: R
+}
+
+impl<P, T> Rechain<P, T> {
+	#[inline]
+	pub(crate) fn new(parent: P, subject: T) -> Self {
+		Rechain { parent, subject, result: () }
+	}
+}
+
+impl<P, T, R> Rechain<P, T, R> {
+	#[inline]
+	/// Calls `f` with the rechained subject, continuing the call chain immutably.
+	pub fn chain<U, F: FnOnce(&T) -> U>(self, f: F) -> Self {
+		f(&self.subject);
+		self
+	}
+
+	#[inline]
+	/// Calls `f` with the rechained subject, continuing the call chain mutably.
+	pub fn chain_mut<U, F: FnOnce(&mut T) -> U>(mut self, f: F) -> Self {
+		f(&mut self.subject);
+		self
+	}
+
+	#[inline]
+	/// Calls `f` with the rechained subject, continuing the call chain immutably with access to its result.
+	pub fn chain_result<U, F: FnOnce(&T) -> U>(self, f: F) -> Rechain<P, T, U> {
+		let result = f(&self.subject);
+		Rechain {
+			parent: self.parent,
+			subject: self.subject,
+			result
+		}
+	}
+
+	#[inline]
+	/// Calls `f` with the rechained subject, continuing the call chain mutably with access to its result.
+	pub fn chain_mut_result<U, F: FnOnce(&mut T) -> U>(mut self, f: F) -> Rechain<P, T, U> {
+		let result = f(&mut self.subject);
+		Rechain {
+			parent: self.parent,
+			subject: self.subject,
+			result
+		}
+	}
+
+	#[inline]
+	/// Returns the result of the last chained function.
+	pub fn result(&self) -> &R {
+		&self.result
+	}
+
+	#[inline]
+	/// Returns the rechained subject.
+	pub fn subject(&self) -> &T {
+		&self.subject
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the rechained subject.
+	pub fn into_subject(self) -> T {
+		self.subject
+	}
+}
+
+impl<'a, S: ?Sized, T, R> Rechain<&'a S, T, R> {
+	#[inline]
+	/// Returns to the original call chain, with the rechained subject as its result.
+	pub fn back(self) -> CallChainResult<'a, S, T> {
+		CallChainResult {
+			this: self.parent,
+			result: self.subject
+		}
+	}
+}
+
+impl<'a, S: ?Sized, T, R> Rechain<&'a mut S, T, R> {
+	#[inline]
+	/// Returns to the original call chain, with the rechained subject as its result.
+	pub fn back(self) -> CallChainResultMut<'a, S, T> {
+		CallChainResultMut {
+			this: self.parent,
+			result: self.subject
+		}
+	}
+}
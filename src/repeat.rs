@@ -0,0 +1,50 @@
+/// The outcome of a repeated chained function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Repetition<R> {
+	/// The number of times the chained function was called.
+	pub iterations: usize,
+
+	/// The result of the last call to the chained function, or `None` if it was never called.
+	pub result: Option<R>
+}
+
+#[inline]
+pub(crate) fn repeat_n<S: ?Sized, R, F: FnMut(&S) -> R>(this: &S, n: usize, mut f: F) -> Repetition<R> {
+	let mut result = None;
+	for _ in 0..n {
+		result = Some(f(this));
+	}
+	Repetition { iterations: n, result }
+}
+
+#[inline]
+pub(crate) fn repeat_n_mut<S: ?Sized, R, F: FnMut(&mut S) -> R>(this: &mut S, n: usize, mut f: F) -> Repetition<R> {
+	let mut result = None;
+	for _ in 0..n {
+		result = Some(f(this));
+	}
+	Repetition { iterations: n, result }
+}
+
+#[inline]
+pub(crate) fn repeat_while_mut<S: ?Sized, R, P: FnMut(&S) -> bool, F: FnMut(&mut S) -> R>(this: &mut S, mut predicate: P, mut f: F) -> Repetition<R> {
+	let mut repetition = Repetition { iterations: 0, result: None };
+	while predicate(this) {
+		repetition.result = Some(f(this));
+		repetition.iterations += 1;
+	}
+	repetition
+}
+
+#[inline]
+pub(crate) fn repeat_until_fixpoint_mut<S: PartialEq + Clone, R, F: FnMut(&mut S) -> R>(this: &mut S, mut f: F) -> Repetition<R> {
+	let mut repetition = Repetition { iterations: 0, result: None };
+	loop {
+		let before = this.clone();
+		repetition.result = Some(f(this));
+		repetition.iterations += 1;
+		if *this == before {
+			break repetition;
+		}
+	}
+}
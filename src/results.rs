@@ -1,4 +1,6 @@
-/// Enables immutable call chaining on all types.
+use crate::{repeat, transaction, ChainError, Chained, FocusCallChain, Kept, Rechain, Repetition, Snapshot, TryCallChainMut};
+
+/// Enables immutable call chaining with access to chained function results on all types.
 ///
 /// # Example
 ///
@@ -15,9 +17,9 @@
 ///
 /// fn main() {
 ///     let value: &'static str = HelloWorld
-///         .chain(HelloWorld::print)
-///         .chain(HelloWorld::print)
-///         .chain(HelloWorld::print)
+///         .chain_result(HelloWorld::print)
+///         .chain_result(HelloWorld::print)
+///         .chain_result(HelloWorld::print)
 ///         .result;
 ///
 ///     // Hello, world!
@@ -26,8 +28,8 @@
 ///     // It works!
 /// }
 /// ```
-pub trait CallChain<S: ?Sized = Self> {
-	/// Enables immutable call chaining on all types.
+pub trait ResultChain {
+	/// Enables immutable call chaining with access to chained function results on all types.
 	///
 	/// # Example
 	///
@@ -44,9 +46,9 @@ pub trait CallChain<S: ?Sized = Self> {
 	///
 	/// fn main() {
 	///     let value: &'static str = HelloWorld
-	///         .chain(HelloWorld::print)
-	///         .chain(HelloWorld::print)
-	///         .chain(HelloWorld::print)
+	///         .chain_result(HelloWorld::print)
+	///         .chain_result(HelloWorld::print)
+	///         .chain_result(HelloWorld::print)
 	///         .result;
 	///
 	///     // Hello, world!
@@ -55,10 +57,114 @@ pub trait CallChain<S: ?Sized = Self> {
 	///     // It works!
 	/// }
 	/// ```
-	fn chain<R, F: FnOnce(&S) -> R>(&self, f: F) -> CallChainResult<'_, S, R>;
+	fn chain_result<R, F: FnOnce(&Self) -> R>(&self, f: F) -> CallChainResult<'_, Self, R>;
+
+	/// Enables immutable call chaining which keeps the result of every chained function.
+	///
+	/// The results are collected into a tuple, in call order.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Greeter;
+	/// impl Greeter {
+	///     fn hello(&self) -> &'static str {
+	///         "Hello"
+	///     }
+	///     fn len(&self) -> usize {
+	///         5
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let (hello, len, world) = Greeter
+	///         .chain_keep(Greeter::hello)
+	///         .chain_keep(Greeter::len)
+	///         .chain_keep(|_| "world")
+	///         .into_results();
+	///
+	///     assert_eq!((hello, len, world), ("Hello", 5, "world"));
+	/// }
+	/// ```
+	fn chain_keep<R, F: FnOnce(&Self) -> R>(&self, f: F) -> CallChainResult<'_, Self, Kept<(R,)>>;
+
+	/// Enables conditional immutable call chaining with access to chained function results on all types.
+	///
+	/// `f` is only called if `condition` is `true`. The result is `None` if it was skipped.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct HelloWorld;
+	/// impl HelloWorld {
+	///     fn print(&self) -> &'static str {
+	///         println!("Hello, world!");
+	///         "It works!"
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let value: Option<&'static str> = HelloWorld
+	///         .chain_result_if(false, HelloWorld::print)
+	///         .result;
+	///
+	///     assert_eq!(value, None);
+	/// }
+	/// ```
+	#[inline]
+	fn chain_result_if<R, F: FnOnce(&Self) -> R>(&self, condition: bool, f: F) -> CallChainResult<'_, Self, Option<R>> {
+		self.chain_result(|this| if condition { Some(f(this)) } else { None })
+	}
+
+	/// Enables conditional immutable call chaining with access to chained function results on all types.
+	///
+	/// `f` is only called if `predicate` returns `true` for the chained subject. The result is `None` if it was skipped.
+	#[inline]
+	fn chain_result_when<P: FnOnce(&Self) -> bool, R, F: FnOnce(&Self) -> R>(&self, predicate: P, f: F) -> CallChainResult<'_, Self, Option<R>> {
+		self.chain_result(|this| if predicate(this) { Some(f(this)) } else { None })
+	}
+
+	/// Enables repeated immutable call chaining with access to chained function results on all types.
+	///
+	/// `f` is called `n` times.
+	#[inline]
+	fn chain_result_n<R, F: FnMut(&Self) -> R>(&self, n: usize, f: F) -> CallChainResult<'_, Self, Repetition<R>> {
+		self.chain_result(|this| repeat::repeat_n(this, n, f))
+	}
+
+	/// Enables immutable call chaining with access to chained function results which borrow from the chained subject, such as getters returning references.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct User { name: String }
+	/// impl User {
+	///     fn name(&self) -> &str {
+	///         &self.name
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let user = User { name: "Ferris".to_string() };
+	///
+	///     let name: &str = user.chain_ref(User::name).result;
+	///
+	///     assert_eq!(name, "Ferris");
+	/// }
+	/// ```
+	#[inline]
+	fn chain_ref<R: ?Sized, F: for<'x> FnOnce(&'x Self) -> &'x R>(&self, f: F) -> CallChainResult<'_, Self, &R> {
+		CallChainResult { result: f(self), this: self }
+	}
 }
 
-/// Enables mutable call chaining on all types.
+/// Enables mutable call chaining with access to chained function results on all types.
 ///
 /// # Example
 ///
@@ -76,9 +182,9 @@ pub trait CallChain<S: ?Sized = Self> {
 ///
 /// fn main() {
 ///     let value: i32 = Counter { value: 0 }
-///         .chain_mut(Counter::increment)
-///         .chain_mut(Counter::increment)
-///         .chain_mut(Counter::increment)
+///         .chain_mut_result(Counter::increment)
+///         .chain_mut_result(Counter::increment)
+///         .chain_mut_result(Counter::increment)
 ///         .result;
 ///
 ///        println!("{value}");
@@ -89,8 +195,8 @@ pub trait CallChain<S: ?Sized = Self> {
 ///     // 3
 /// }
 /// ```
-pub trait CallChainMut<S: ?Sized = Self> {
-	/// Enables mutable call chaining on all types.
+pub trait ResultChainMut {
+	/// Enables mutable call chaining with access to chained function results on all types.
 	///
 	/// # Example
 	///
@@ -108,9 +214,9 @@ pub trait CallChainMut<S: ?Sized = Self> {
 	///
 	/// fn main() {
 	///     let value: i32 = Counter { value: 0 }
-	///         .chain_mut(Counter::increment)
-	///         .chain_mut(Counter::increment)
-	///         .chain_mut(Counter::increment)
+	///         .chain_mut_result(Counter::increment)
+	///         .chain_mut_result(Counter::increment)
+	///         .chain_mut_result(Counter::increment)
 	///         .result;
 	///
 	///        println!("{value}");
@@ -121,41 +227,260 @@ pub trait CallChainMut<S: ?Sized = Self> {
 	///     // 3
 	/// }
 	/// ```
-	fn chain_mut<R, F: FnOnce(&mut S) -> R>(&mut self, f: F) -> CallChainResultMut<'_, S, R>;
+	fn chain_mut_result<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> CallChainResultMut<'_, Self, R>;
+
+	/// Enables mutable call chaining which keeps the result of every chained function.
+	///
+	/// The results are collected into a tuple, in call order.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Counter { value: i32 }
+	/// impl Counter {
+	///     fn increment(&mut self) -> i32 {
+	///         self.value += 1;
+	///         self.value
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let results = Counter { value: 0 }
+	///         .chain_mut_keep(Counter::increment)
+	///         .chain_mut_keep(Counter::increment)
+	///         .chain_keep(|counter| counter.value * 10)
+	///         .into_results();
+	///
+	///     assert_eq!(results, (1, 2, 20));
+	/// }
+	/// ```
+	fn chain_mut_keep<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> CallChainResultMut<'_, Self, Kept<(R,)>>;
+
+	/// Enables conditional mutable call chaining with access to chained function results on all types.
+	///
+	/// `f` is only called if `condition` is `true`. The result is `None` if it was skipped.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Counter { value: i32 }
+	/// impl Counter {
+	///     fn increment(&mut self) -> i32 {
+	///         self.value += 1;
+	///         self.value
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let value: Option<i32> = Counter { value: 0 }
+	///         .chain_mut_result_if(true, Counter::increment)
+	///         .chain_mut_result_when(|counter| counter.value > 1, Counter::increment)
+	///         .result;
+	///
+	///     assert_eq!(value, None);
+	/// }
+	/// ```
+	#[inline]
+	fn chain_mut_result_if<R, F: FnOnce(&mut Self) -> R>(&mut self, condition: bool, f: F) -> CallChainResultMut<'_, Self, Option<R>> {
+		self.chain_mut_result(|this| if condition { Some(f(this)) } else { None })
+	}
+
+	/// Enables conditional mutable call chaining with access to chained function results on all types.
+	///
+	/// `f` is only called if `predicate` returns `true` for the chained subject. The result is `None` if it was skipped.
+	#[inline]
+	fn chain_mut_result_when<P: FnOnce(&Self) -> bool, R, F: FnOnce(&mut Self) -> R>(&mut self, predicate: P, f: F) -> CallChainResultMut<'_, Self, Option<R>> {
+		self.chain_mut_result(|this| if predicate(this) { Some(f(this)) } else { None })
+	}
+
+	/// Enables repeated mutable call chaining with access to chained function results on all types.
+	///
+	/// `f` is called `n` times. The result holds the number of iterations and the result of the last call.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Counter { value: i32 }
+	/// impl Counter {
+	///     fn increment(&mut self) -> i32 {
+	///         self.value += 1;
+	///         self.value
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let repetition = Counter { value: 0 }
+	///         .chain_mut_result_n(3, Counter::increment)
+	///         .chain_mut_result_while(|counter| counter.value < 10, Counter::increment)
+	///         .result;
+	///
+	///     assert_eq!(repetition, Repetition { iterations: 7, result: Some(10) });
+	/// }
+	/// ```
+	#[inline]
+	fn chain_mut_result_n<R, F: FnMut(&mut Self) -> R>(&mut self, n: usize, f: F) -> CallChainResultMut<'_, Self, Repetition<R>> {
+		self.chain_mut_result(|this| repeat::repeat_n_mut(this, n, f))
+	}
+
+	/// Enables repeated mutable call chaining with access to chained function results on all types.
+	///
+	/// `f` is called for as long as `predicate` returns `true` for the chained subject. The result holds the number of iterations and the result of the last call.
+	#[inline]
+	fn chain_mut_result_while<P: FnMut(&Self) -> bool, R, F: FnMut(&mut Self) -> R>(&mut self, predicate: P, f: F) -> CallChainResultMut<'_, Self, Repetition<R>> {
+		self.chain_mut_result(|this| repeat::repeat_while_mut(this, predicate, f))
+	}
+
+	/// Enables repeated mutable call chaining with access to chained function results on all types.
+	///
+	/// `f` is called until the chained subject stops changing. The result holds the number of iterations and the result of the last call.
+	#[inline]
+	fn chain_mut_result_until_fixpoint<R, F: FnMut(&mut Self) -> R>(&mut self, f: F) -> CallChainResultMut<'_, Self, Repetition<R>>
+	where
+		Self: PartialEq + Clone
+	{
+		self.chain_mut_result(|this| repeat::repeat_until_fixpoint_mut(this, f))
+	}
+
+	/// Calls `f` with the mutable chained subject and returns its result, which mutably borrows from the chained subject, such as a getter returning a mutable reference.
+	///
+	/// As the result keeps the chained subject borrowed, the call chain ends here.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct User { name: String }
+	/// impl User {
+	///     fn name_mut(&mut self) -> &mut String {
+	///         &mut self.name
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let mut user = User { name: "Ferris".to_string() };
+	///
+	///     user
+	///         .chain_mut_result(|user| user.name.clear())
+	///         .chain_mut_ref(User::name_mut)
+	///         .push_str("Corro");
+	///
+	///     assert_eq!(user.name, "Corro");
+	/// }
+	/// ```
+	#[inline]
+	fn chain_mut_ref<R: ?Sized, F: for<'x> FnOnce(&'x mut Self) -> &'x mut R>(&mut self, f: F) -> &mut R {
+		f(self)
+	}
 }
-impl<T: ?Sized> CallChain for T {
+impl<T: ?Sized + Chained> ResultChain for T {
 	#[inline]
-	default fn chain<R, F: FnOnce(&T) -> R>(&self, f: F) -> CallChainResult<'_, T, R> {
+	fn chain_result<R, F: FnOnce(&T) -> R>(&self, f: F) -> CallChainResult<'_, T, R> {
 		CallChainResult {
 			result: f(self),
 			this: self
 		}
 	}
+
+	#[inline]
+	fn chain_keep<R, F: FnOnce(&T) -> R>(&self, f: F) -> CallChainResult<'_, T, Kept<(R,)>> {
+		CallChainResult {
+			result: Kept((f(self),)),
+			this: self
+		}
+	}
 }
-impl<T: ?Sized> CallChainMut for T {
+impl<T: ?Sized + Chained> ResultChainMut for T {
 	#[inline]
-	default fn chain_mut<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> CallChainResultMut<'_, T, R> {
+	fn chain_mut_result<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> CallChainResultMut<'_, T, R> {
 		CallChainResultMut {
 			result: f(self),
 			this: self
 		}
 	}
+
+	#[inline]
+	fn chain_mut_keep<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> CallChainResultMut<'_, T, Kept<(R,)>> {
+		CallChainResultMut {
+			result: Kept((f(self),)),
+			this: self
+		}
+	}
 }
 
 /// A result from a call chain. Dereferences to the return value but can also be used to chain further, immutably.
+///
+/// Comparisons, hashing and formatting are forwarded to the result.
 pub struct CallChainResult<'a, S: ?Sized, R> {
-	this: &'a S,
+	pub(crate) this: &'a S,
 
 	/// The result of the chained function.
 	pub result: R
 }
 
-impl<S: ?Sized, R> CallChainResult<'_, S, R> {
+impl<'a, S: ?Sized, R> CallChainResult<'a, S, R> {
 	#[inline]
 	/// Returns the result of the chained function.
 	pub fn into_result(self) -> R {
 		self.result
 	}
+
+	#[inline]
+	/// Returns the chained subject, borrowed for as long as the call chain's own borrow of it.
+	pub fn subject(&self) -> &'a S {
+		self.this
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the chained subject.
+	pub fn into_subject(self) -> &'a S {
+		self.this
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the chained subject and the result of the chained function.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Counter { value: i32 }
+	///
+	/// fn main() {
+	///     let counter = Counter { value: 1 };
+	///
+	///     let (counter, doubled): (&Counter, i32) = counter
+	///         .chain_result(|counter| counter.value * 2)
+	///         .into_parts();
+	///
+	///     assert_eq!((counter.value, doubled), (1, 2));
+	/// }
+	/// ```
+	pub fn into_parts(self) -> (&'a S, R) {
+		(self.this, self.result)
+	}
+
+	#[inline]
+	/// Starts a new call chain on the result, keeping this call chain to return to with [`Rechain::back`].
+	pub fn rechain(self) -> Rechain<&'a S, R> {
+		Rechain::new(self.this, self.result)
+	}
+
+	#[inline]
+	/// Starts a new mutable call chain on the borrowed result. This call chain can be used again once the new one ends.
+	pub fn rechain_mut(&mut self) -> CallChainResultMut<'_, R, ()> {
+		CallChainResultMut {
+			this: &mut self.result,
+			result: ()
+		}
+	}
 }
 
 impl<S: ?Sized, R> AsRef<S> for CallChainResult<'_, S, R> {
@@ -180,30 +505,166 @@ impl<S: ?Sized, R> core::ops::DerefMut for CallChainResult<'_, S, R> {
 	}
 }
 
-impl<S: ?Sized, T> CallChain<S> for CallChainResult<'_, S, T> {
+impl<'a, S: ?Sized, T> CallChainResult<'a, S, T> {
+	#[inline]
+	/// Calls `f` with the chained subject, continuing the call chain immutably.
+	pub fn chain_result<R, F: FnOnce(&S) -> R>(&self, f: F) -> CallChainResult<'a, S, R> {
+		CallChainResult {
+			result: f(self.this),
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject if `condition` is `true`, continuing the call chain immutably.
+	pub fn chain_result_if<R, F: FnOnce(&S) -> R>(&self, condition: bool, f: F) -> CallChainResult<'a, S, Option<R>> {
+		self.chain_result(|this| if condition { Some(f(this)) } else { None })
+	}
+
 	#[inline]
-	fn chain<R, F: FnOnce(&S) -> R>(&self, f: F) -> CallChainResult<'_, S, R> {
+	/// Calls `f` with the chained subject if `predicate` returns `true` for it, continuing the call chain immutably.
+	pub fn chain_result_when<P: FnOnce(&S) -> bool, R, F: FnOnce(&S) -> R>(&self, predicate: P, f: F) -> CallChainResult<'a, S, Option<R>> {
+		self.chain_result(|this| if predicate(this) { Some(f(this)) } else { None })
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject `n` times, continuing the call chain immutably.
+	pub fn chain_result_n<R, F: FnMut(&S) -> R>(&self, n: usize, f: F) -> CallChainResult<'a, S, Repetition<R>> {
+		self.chain_result(|this| repeat::repeat_n(this, n, f))
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject, continuing the call chain immutably with a result which borrows from the chained subject.
+	pub fn chain_ref<R: ?Sized, F: for<'x> FnOnce(&'x S) -> &'x R>(&self, f: F) -> CallChainResult<'a, S, &'a R> {
 		CallChainResult {
 			result: f(self.this),
 			this: self.this
 		}
 	}
+
+	#[cfg(feature = "std")]
+	#[inline]
+	/// Calls `f` with the chained subject, catching any panic, continuing the call chain immutably.
+	///
+	/// See [`CallChainExt::chain_catch`](crate::CallChainExt::chain_catch).
+	pub fn chain_catch<R, F: FnOnce(&S) -> R>(&self, policy: crate::CatchPolicy, f: F) -> crate::CatchCallChain<'a, S, R> {
+		crate::CatchCallChain::new(self.this, policy, f)
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject and the result of the previous chained function, continuing the call chain immutably.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Multiplier { factor: i32 }
+	/// impl Multiplier {
+	///     fn start(&self) -> i32 {
+	///         1
+	///     }
+	///     fn apply(&self, value: i32) -> i32 {
+	///         value * self.factor
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let value = Multiplier { factor: 3 }
+	///         .chain_result(Multiplier::start)
+	///         .chain_with(Multiplier::apply)
+	///         .chain_with(Multiplier::apply)
+	///         .result;
+	///
+	///     assert_eq!(value, 9);
+	/// }
+	/// ```
+	pub fn chain_with<R, F: FnOnce(&S, T) -> R>(self, f: F) -> CallChainResult<'a, S, R> {
+		CallChainResult {
+			result: f(self.this, self.result),
+			this: self.this
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[track_caller]
+	/// Prints the [`Debug`](core::fmt::Debug) output of the result to stderr, along with the file and line of the call, like [`dbg!`](std::dbg).
+	pub fn chain_dbg(self) -> Self
+	where
+		T: core::fmt::Debug
+	{
+		crate::inspect::dbg(core::panic::Location::caller(), &self.result);
+		self
+	}
+
+	#[inline]
+	/// Calls `f` with the result in debug builds only.
+	///
+	/// `f` is only called when `debug_assertions` are enabled, and is compiled away otherwise.
+	pub fn chain_debug_only<R, F: FnOnce(&T) -> R>(self, f: F) -> Self {
+		#[cfg(debug_assertions)]
+		f(&self.result);
+		#[cfg(not(debug_assertions))]
+		let _ = f;
+		self
+	}
 }
 
 /// A result from a call chain. Dereferences to the return value but can also be used to chain further, mutably.
+///
+/// Comparisons, hashing and formatting are forwarded to the result.
 pub struct CallChainResultMut<'a, S: ?Sized, R> {
-	this: &'a mut S,
+	pub(crate) this: &'a mut S,
 
 	/// The result of the chained function.
 	pub result: R
 }
 
-impl<S: ?Sized, R> CallChainResultMut<'_, S, R> {
+impl<'a, S: ?Sized, R> CallChainResultMut<'a, S, R> {
 	#[inline]
 	/// Returns the result of the chained function.
 	pub fn into_result(self) -> R {
 		self.result
 	}
+
+	#[inline]
+	/// Returns the chained subject.
+	pub fn subject(&self) -> &S {
+		self.this
+	}
+
+	#[inline]
+	/// Returns the chained subject mutably.
+	pub fn subject_mut(&mut self) -> &mut S {
+		self.this
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the chained subject.
+	pub fn into_subject(self) -> &'a mut S {
+		self.this
+	}
+
+	#[inline]
+	/// Ends the call chain, returning the chained subject and the result of the chained function.
+	pub fn into_parts(self) -> (&'a mut S, R) {
+		(self.this, self.result)
+	}
+
+	#[inline]
+	/// Starts a new call chain on the result, keeping this call chain to return to with [`Rechain::back`].
+	pub fn rechain(self) -> Rechain<&'a mut S, R> {
+		Rechain::new(self.this, self.result)
+	}
+
+	#[inline]
+	/// Starts a new mutable call chain on the borrowed result. This call chain can be used again once the new one ends.
+	pub fn rechain_mut(&mut self) -> CallChainResultMut<'_, R, ()> {
+		CallChainResultMut {
+			this: &mut self.result,
+			result: ()
+		}
+	}
 }
 
 impl<S: ?Sized, R> AsRef<S> for CallChainResultMut<'_, S, R> {
@@ -234,21 +695,309 @@ impl<S: ?Sized, R> core::ops::DerefMut for CallChainResultMut<'_, S, R> {
 	}
 }
 
-impl<S: ?Sized, T> CallChain<S> for CallChainResultMut<'_, S, T> {
+/// Forwards the standard comparison, hashing and formatting traits of a call chain result to its `result`.
+macro_rules! impl_result_traits {
+	($($wrapper:ident),*) => {$(
+		impl<S: ?Sized, R: core::fmt::Debug> core::fmt::Debug for $wrapper<'_, S, R> {
+			fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+				f.debug_struct(stringify!($wrapper)).field("result", &self.result).finish_non_exhaustive()
+			}
+		}
+
+		impl<S: ?Sized, R: core::fmt::Display> core::fmt::Display for $wrapper<'_, S, R> {
+			#[inline]
+			fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+				self.result.fmt(f)
+			}
+		}
+
+		impl<S: ?Sized, T: PartialEq<U>, U> PartialEq<$wrapper<'_, S, U>> for $wrapper<'_, S, T> {
+			#[inline]
+			fn eq(&self, other: &$wrapper<'_, S, U>) -> bool {
+				self.result == other.result
+			}
+		}
+
+		impl<S: ?Sized, R: Eq> Eq for $wrapper<'_, S, R> {}
+
+		impl<S: ?Sized, T: PartialOrd<U>, U> PartialOrd<$wrapper<'_, S, U>> for $wrapper<'_, S, T> {
+			#[inline]
+			fn partial_cmp(&self, other: &$wrapper<'_, S, U>) -> Option<core::cmp::Ordering> {
+				self.result.partial_cmp(&other.result)
+			}
+		}
+
+		impl<S: ?Sized, R: Ord> Ord for $wrapper<'_, S, R> {
+			#[inline]
+			fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+				self.result.cmp(&other.result)
+			}
+		}
+
+		impl<S: ?Sized, R: core::hash::Hash> core::hash::Hash for $wrapper<'_, S, R> {
+			#[inline]
+			fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+				self.result.hash(state)
+			}
+		}
+	)*};
+}
+impl_result_traits!(CallChainResult, CallChainResultMut);
+
+impl<S: ?Sized, R: Clone> Clone for CallChainResult<'_, S, R> {
 	#[inline]
-	fn chain<R, F: FnOnce(&S) -> R>(&self, f: F) -> CallChainResult<'_, S, R> {
+	fn clone(&self) -> Self {
 		CallChainResult {
-			result: f(self.this),
+			result: self.result.clone(),
 			this: self.this
 		}
 	}
 }
-impl<S: ?Sized, T> CallChainMut<S> for CallChainResultMut<'_, S, T> {
+impl<S: ?Sized, R: Copy> Copy for CallChainResult<'_, S, R> {}
+
+impl<'a, S: ?Sized, R> From<CallChainResult<'a, S, R>> for (&'a S, R) {
 	#[inline]
-	fn chain_mut<R, F: FnOnce(&mut S) -> R>(&mut self, f: F) -> CallChainResultMut<'_, S, R> {
+	fn from(result: CallChainResult<'a, S, R>) -> (&'a S, R) {
+		result.into_parts()
+	}
+}
+impl<'a, S: ?Sized, R> From<CallChainResultMut<'a, S, R>> for (&'a mut S, R) {
+	#[inline]
+	fn from(result: CallChainResultMut<'a, S, R>) -> (&'a mut S, R) {
+		result.into_parts()
+	}
+}
+
+impl<'a, S: ?Sized, T> CallChainResultMut<'a, S, T> {
+	#[inline]
+	/// Calls `f` with the chained subject immutably, continuing the call chain mutably.
+	pub fn chain_result<R, F: FnOnce(&S) -> R>(self, f: F) -> CallChainResultMut<'a, S, R> {
 		CallChainResultMut {
 			result: f(self.this),
 			this: self.this
 		}
 	}
+
+	#[inline]
+	/// Calls `f` with the chained subject immutably if `condition` is `true`, continuing the call chain mutably.
+	pub fn chain_result_if<R, F: FnOnce(&S) -> R>(self, condition: bool, f: F) -> CallChainResultMut<'a, S, Option<R>> {
+		self.chain_result(|this| if condition { Some(f(this)) } else { None })
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject immutably if `predicate` returns `true` for it, continuing the call chain mutably.
+	pub fn chain_result_when<P: FnOnce(&S) -> bool, R, F: FnOnce(&S) -> R>(self, predicate: P, f: F) -> CallChainResultMut<'a, S, Option<R>> {
+		self.chain_result(|this| if predicate(this) { Some(f(this)) } else { None })
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject immutably `n` times, continuing the call chain mutably.
+	pub fn chain_result_n<R, F: FnMut(&S) -> R>(self, n: usize, f: F) -> CallChainResultMut<'a, S, Repetition<R>> {
+		self.chain_result(|this| repeat::repeat_n(this, n, f))
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject, continuing the call chain immutably with a result which borrows from the chained subject.
+	///
+	/// As the result keeps the chained subject borrowed, the rest of the call chain is immutable.
+	pub fn chain_ref<R: ?Sized, F: for<'x> FnOnce(&'x S) -> &'x R>(self, f: F) -> CallChainResult<'a, S, &'a R> {
+		let this: &'a S = self.this;
+		CallChainResult { result: f(this), this }
+	}
+
+	#[cfg(feature = "std")]
+	#[inline]
+	/// Calls `f` with the chained subject immutably, catching any panic, continuing the call chain mutably.
+	///
+	/// See [`CallChainExt::chain_catch`](crate::CallChainExt::chain_catch).
+	pub fn chain_catch<R, F: FnOnce(&S) -> R>(self, policy: crate::CatchPolicy, f: F) -> crate::CatchCallChainMut<'a, S, R> {
+		crate::CatchCallChainMut::new(self.this, policy, |this| f(this))
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject, continuing the call chain mutably.
+	pub fn chain_mut_result<R, F: FnOnce(&mut S) -> R>(self, f: F) -> CallChainResultMut<'a, S, R> {
+		CallChainResultMut {
+			result: f(self.this),
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject if `condition` is `true`, continuing the call chain mutably.
+	pub fn chain_mut_result_if<R, F: FnOnce(&mut S) -> R>(self, condition: bool, f: F) -> CallChainResultMut<'a, S, Option<R>> {
+		self.chain_mut_result(|this| if condition { Some(f(this)) } else { None })
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject if `predicate` returns `true` for it, continuing the call chain mutably.
+	pub fn chain_mut_result_when<P: FnOnce(&S) -> bool, R, F: FnOnce(&mut S) -> R>(self, predicate: P, f: F) -> CallChainResultMut<'a, S, Option<R>> {
+		self.chain_mut_result(|this| if predicate(this) { Some(f(this)) } else { None })
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject `n` times, continuing the call chain mutably.
+	pub fn chain_mut_result_n<R, F: FnMut(&mut S) -> R>(self, n: usize, f: F) -> CallChainResultMut<'a, S, Repetition<R>> {
+		self.chain_mut_result(|this| repeat::repeat_n_mut(this, n, f))
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject for as long as `predicate` returns `true` for it, continuing the call chain mutably.
+	pub fn chain_mut_result_while<P: FnMut(&S) -> bool, R, F: FnMut(&mut S) -> R>(self, predicate: P, f: F) -> CallChainResultMut<'a, S, Repetition<R>> {
+		self.chain_mut_result(|this| repeat::repeat_while_mut(this, predicate, f))
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject until it stops changing, continuing the call chain mutably.
+	pub fn chain_mut_result_until_fixpoint<R, F: FnMut(&mut S) -> R>(self, f: F) -> CallChainResultMut<'a, S, Repetition<R>>
+	where
+		S: PartialEq + Clone
+	{
+		self.chain_mut_result(|this| repeat::repeat_until_fixpoint_mut(this, f))
+	}
+
+	#[inline]
+	/// Calls `f` with the mutable chained subject and returns its result, which mutably borrows from the chained subject.
+	///
+	/// As the result keeps the chained subject borrowed, the call chain ends here.
+	pub fn chain_mut_ref<R: ?Sized, F: for<'x> FnOnce(&'x mut S) -> &'x mut R>(self, f: F) -> &'a mut R {
+		f(self.this)
+	}
+
+	#[cfg(feature = "std")]
+	#[inline]
+	/// Calls `f` with the chained subject, catching any panic, continuing the call chain mutably.
+	///
+	/// See [`CallChainMut::chain_mut_catch`](crate::CallChainMut::chain_mut_catch).
+	pub fn chain_mut_catch<R, F: FnOnce(&mut S) -> R>(self, policy: crate::CatchPolicy, f: F) -> crate::CatchCallChainMut<'a, S, R> {
+		crate::CatchCallChainMut::new(self.this, policy, f)
+	}
+
+	#[inline]
+	/// Runs the fallible call chain returned by `f` on the chained subject as a transaction, continuing the call chain mutably with its outcome.
+	///
+	/// The chained subject is cloned beforehand and restored from the clone if any step fails or panics. See [`CallChainMut::transaction`](crate::CallChainMut::transaction).
+	pub fn transaction<R, E, F>(self, f: F) -> CallChainResultMut<'a, S, Result<R, ChainError<E>>>
+	where
+		S: Clone,
+		F: for<'t> FnOnce(&'t mut S) -> TryCallChainMut<'t, S, R, E>
+	{
+		self.chain_mut_result(|this| transaction::run(this, this.clone(), |this, snapshot| *this = snapshot, f).1)
+	}
+
+	#[inline]
+	/// Runs the fallible call chain returned by `f` on the chained subject as a transaction, continuing the call chain mutably with its outcome.
+	///
+	/// Like [`CallChainResultMut::transaction`], but the chained subject is saved and restored with its [`Snapshot`] implementation.
+	pub fn transaction_snapshot<R, E, F>(self, f: F) -> CallChainResultMut<'a, S, Result<R, ChainError<E>>>
+	where
+		S: Snapshot,
+		F: for<'t> FnOnce(&'t mut S) -> TryCallChainMut<'t, S, R, E>
+	{
+		self.chain_mut_result(|this| transaction::run(this, this.snapshot(), S::restore, f).1)
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject immutably and the result of the previous chained function, continuing the call chain mutably.
+	pub fn chain_with<R, F: FnOnce(&S, T) -> R>(self, f: F) -> CallChainResultMut<'a, S, R> {
+		let result = f(self.this, self.result);
+		CallChainResultMut {
+			result,
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Calls `f` with the chained subject and the result of the previous chained function, continuing the call chain mutably.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Buffer { data: Vec<u8> }
+	/// impl Buffer {
+	///     fn reserve(&mut self) -> usize {
+	///         self.data.push(0);
+	///         self.data.len() - 1
+	///     }
+	///     fn fill(&mut self, index: usize) -> u8 {
+	///         self.data[index] = 42;
+	///         self.data[index]
+	///     }
+	/// }
+	///
+	/// fn main() {
+	///     let mut buffer = Buffer { data: Vec::new() };
+	///
+	///     let value = buffer
+	///         .chain_mut_result(Buffer::reserve)
+	///         .chain_mut_with(Buffer::fill)
+	///         .result;
+	///
+	///     assert_eq!(value, 42);
+	///     assert_eq!(buffer.data, [42]);
+	/// }
+	/// ```
+	pub fn chain_mut_with<R, F: FnOnce(&mut S, T) -> R>(self, f: F) -> CallChainResultMut<'a, S, R> {
+		let result = f(&mut *self.this, self.result);
+		CallChainResultMut {
+			result,
+			this: self.this
+		}
+	}
+
+	#[inline]
+	/// Focuses the call chain on a part of the chained subject, such as a field, keeping the result of the previous chained function.
+	///
+	/// Calling [`FocusCallChain::unfocus`] returns to the chained subject with the result of the last chained function.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use chainer::*;
+	///
+	/// struct Inner { value: i32 }
+	/// struct Outer { inner: Inner, total: i32 }
+	///
+	/// fn main() {
+	///     let mut outer = Outer { inner: Inner { value: 1 }, total: 0 };
+	///
+	///     let total = outer
+	///         .chain_mut_result(|outer| outer.total)
+	///         .focus_mut(|outer| &mut outer.inner)
+	///         .chain_mut_result(|inner| { inner.value *= 10; inner.value })
+	///         .unfocus()
+	///         .chain_mut_with(|outer, value| { outer.total += value; outer.total })
+	///         .result;
+	///
+	///     assert_eq!(total, 10);
+	/// }
+	/// ```
+	pub fn focus_mut<U: ?Sized, G: Fn(&mut S) -> &mut U>(self, focus: G) -> FocusCallChain<'a, S, U, G, T> {
+		FocusCallChain::new(self.this, focus, self.result)
+	}
+
+	#[cfg(feature = "std")]
+	#[track_caller]
+	/// Prints the [`Debug`](core::fmt::Debug) output of the result to stderr, along with the file and line of the call, like [`dbg!`](std::dbg).
+	pub fn chain_dbg(self) -> Self
+	where
+		T: core::fmt::Debug
+	{
+		crate::inspect::dbg(core::panic::Location::caller(), &self.result);
+		self
+	}
+
+	#[inline]
+	/// Calls `f` with the result in debug builds only.
+	///
+	/// `f` is only called when `debug_assertions` are enabled, and is compiled away otherwise.
+	pub fn chain_debug_only<R, F: FnOnce(&T) -> R>(self, f: F) -> Self {
+		#[cfg(debug_assertions)]
+		f(&self.result);
+		#[cfg(not(debug_assertions))]
+		let _ = f;
+		self
+	}
 }
\ No newline at end of file
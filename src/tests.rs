@@ -19,7 +19,6 @@ fn test_immutable() {
 	assert_eq!(COUNTER.load(core::sync::atomic::Ordering::Relaxed), 3);
 }
 
-#[cfg(not(feature = "results"))]
 #[test]
 fn test_basic_mutable() {
 	struct Counter { value: i32 }
@@ -52,10 +51,1325 @@ fn test_results_mutable() {
 	}
 
 	let result = Counter { value: 0 }
+		.chain_mut_result(Counter::increment)
+		.chain_mut_result(Counter::increment)
+		.chain_mut_result(Counter::increment)
+		.result;
+
+	assert_eq!(result, 3);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_closures() {
+	struct Counter { value: i32 }
+
+	let result = Counter { value: 1 }
+		.chain_result(|counter| counter.value)
+		.chain_result(|counter| counter.value + 1)
+		.result;
+
+	assert_eq!(result, 2);
+
+	let result = Counter { value: 0 }
+		.chain_mut_result(|counter| counter.value += 1)
+		.chain_mut_result(|counter| counter.value * 10)
+		.result;
+
+	assert_eq!(result, 10);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_additive() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) -> i32 {
+			self.value += 1;
+			self.value
+		}
+	}
+
+	let mut counter = Counter { value: 0 };
+
+	let this: &mut Counter = counter
 		.chain_mut(Counter::increment)
+		.chain_mut(Counter::increment);
+
+	let result = this
+		.chain_mut_result(Counter::increment)
+		.result;
+
+	assert_eq!(result, 3);
+	assert_eq!(counter.value, 3);
+}
+
+#[test]
+fn test_try_chain() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) -> Result<i32, i32> {
+			if self.value < 2 {
+				self.value += 1;
+				Ok(self.value)
+			} else {
+				Err(self.value)
+			}
+		}
+	}
+
+	let mut counter = Counter { value: 0 };
+
+	let error = counter
+		.try_chain_mut(Counter::increment)
+		.try_chain(|counter| Some(counter.value).ok_or(0))
+		.try_chain_mut(Counter::increment)
+		.try_chain_mut(Counter::increment)
+		.try_chain_mut(Counter::increment)
+		.into_result()
+		.err();
+
+	assert_eq!(error, Some(ChainError { step: 3, error: 2 }));
+	assert_eq!(counter.value, 2);
+
+	let value = Counter { value: 0 }
+		.try_chain(|counter| Some(counter.value))
+		.try_chain(|_| None::<()>)
+		.into_result()
+		.map(|counter| counter.value);
+
+	assert_eq!(value, Err(ChainError { step: 1, error: NoneError }));
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_try_chain() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) -> Option<i32> {
+			self.value += 1;
+			Some(self.value)
+		}
+	}
+
+	let result = Counter { value: 0 }
+		.try_chain_mut(Counter::increment)
+		.try_chain_mut(Counter::increment)
+		.into_chain_result()
+		.map(|result| result.into_result());
+
+	assert_eq!(result, Ok(2));
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_chain_keep() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) -> i32 {
+			self.value += 1;
+			self.value
+		}
+	}
+
+	let mut counter = Counter { value: 0 };
+
+	let results = counter
+		.chain_mut_result(Counter::increment)
+		.chain_mut_keep(Counter::increment)
+		.chain_keep(|counter| counter.value > 1)
+		.chain_mut_keep(Counter::increment)
+		.into_results();
+
+	assert_eq!(results, (1, 2, true, 3));
+	assert_eq!(counter.value, 3);
+
+	let results = counter
+		.chain_result(|counter| (counter.value, counter.value))
+		.chain_keep(|counter| counter.value + 1)
+		.into_results();
+
+	assert_eq!(results, ((3, 3), 4));
+
+	let results = counter
+		.chain_result(|counter| counter.value)
+		.chain_keep(|counter| counter.value + 1)
+		.chain_keep(|counter| counter.value + 2)
+		.into_results();
+
+	assert_eq!(results, (3, 4, 5));
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_chain_with() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn add(&mut self, amount: i32) -> i32 {
+			self.value += amount;
+			self.value
+		}
+	}
+
+	let mut counter = Counter { value: 1 };
+
+	let result = counter
+		.chain_mut_result(|counter| counter.value)
+		.chain_mut_with(Counter::add)
+		.chain_with(|counter, value| counter.value + value)
+		.chain_mut_with(Counter::add)
+		.result;
+
+	assert_eq!(result, 6);
+	assert_eq!(counter.value, 6);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_combinators() {
+	struct Parser { input: &'static str }
+	impl Parser {
+		fn parse(&self) -> Result<i32, core::num::ParseIntError> {
+			self.input.parse()
+		}
+	}
+
+	let parser = Parser { input: "21" };
+
+	let result = parser
+		.chain_result(Parser::parse)
+		.and_then_result(|value| Ok(value * 2))
+		.unwrap_result_or(0)
+		.zip_result(|parser| parser.input.len())
+		.result;
+
+	assert_eq!(result, (42, 2));
+
+	let result = Parser { input: "chainer" }
+		.chain_result(Parser::parse)
+		.unwrap_result_or_else(|_| -1)
+		.chain_with(|parser, value| parser.input.len() as i32 + value)
+		.result;
+
+	assert_eq!(result, 6);
+
+	let mut values = [1, 2, 3];
+
+	let result = values
+		.chain_mut_result(|values| values.iter().position(|&value| value == 4))
+		.unwrap_result_or_default()
+		.chain_mut_with(|values, index| core::mem::replace(&mut values[index], 0))
+		.replace_result("replaced")
+		.result;
+
+	assert_eq!(result, "replaced");
+	assert_eq!(values, [0, 2, 3]);
+}
+
+#[test]
+fn test_conditional() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) {
+			self.value += 1;
+		}
+	}
+
+	let mut counter = Counter { value: 0 };
+
+	counter
+		.chain_mut_if(true, Counter::increment)
+		.chain_mut_if(false, Counter::increment)
+		.chain_mut_when(|counter| counter.value == 1, Counter::increment)
+		.chain_mut_when(|counter| counter.value == 1, Counter::increment)
+		.chain_if(false, |_| unreachable!())
+		.chain_when(|counter| counter.value == 2, |counter| assert_eq!(counter.value, 2));
+
+	assert_eq!(counter.value, 2);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_conditional() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) -> i32 {
+			self.value += 1;
+			self.value
+		}
+	}
+
+	let mut counter = Counter { value: 0 };
+
+	let result = counter
+		.chain_mut_result_if(true, Counter::increment)
+		.chain_mut_result_if(false, Counter::increment)
+		.result;
+
+	assert_eq!(result, None);
+
+	let result = counter
+		.chain_mut_result_when(|counter| counter.value == 1, Counter::increment)
+		.chain_result_when(|counter| counter.value == 2, |counter| counter.value * 10)
+		.result;
+
+	assert_eq!(result, Some(20));
+	assert_eq!(counter.value, 2);
+}
+
+#[test]
+fn test_repetition() {
+	#[derive(Clone, PartialEq)]
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) -> i32 {
+			self.value += 1;
+			self.value
+		}
+		fn halve(&mut self) {
+			self.value /= 2;
+		}
+	}
+
+	let mut counter = Counter { value: 0 };
+
+	counter
+		.chain_mut_n(3, Counter::increment)
+		.chain_mut_while(|counter| counter.value < 8, Counter::increment)
+		.chain_mut_n(0, |_| unreachable!());
+
+	assert_eq!(counter.value, 8);
+
+	counter.chain_mut_until_fixpoint(Counter::halve);
+
+	assert_eq!(counter.value, 0);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_repetition() {
+	#[derive(Clone, PartialEq)]
+	struct Counter { value: i32 }
+	impl Counter {
+		fn halve(&mut self) -> i32 {
+			self.value /= 2;
+			self.value
+		}
+	}
+
+	let mut counter = Counter { value: 8 };
+
+	let repetition = counter
+		.chain_mut_result_while(|counter| counter.value > 8, Counter::halve)
+		.result;
+
+	assert_eq!(repetition, Repetition { iterations: 0, result: None });
+
+	let repetition = counter
+		.chain_mut_result_until_fixpoint(Counter::halve)
+		.result;
+
+	assert_eq!(repetition, Repetition { iterations: 5, result: Some(0) });
+
+	let repetition = counter
+		.chain_result_n(2, |counter| counter.value + 1)
+		.result;
+
+	assert_eq!(repetition, Repetition { iterations: 2, result: Some(1) });
+}
+
+fn block_on<F: core::future::Future>(future: F) -> F::Output {
+	let mut future = core::pin::pin!(future);
+	let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+	loop {
+		if let core::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+			break output;
+		}
+	}
+}
+
+struct Yield(bool);
+impl core::future::Future for Yield {
+	type Output = ();
+
+	fn poll(mut self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<()> {
+		if core::mem::replace(&mut self.0, true) {
+			core::task::Poll::Ready(())
+		} else {
+			cx.waker().wake_by_ref();
+			core::task::Poll::Pending
+		}
+	}
+}
+
+#[test]
+fn test_async() {
+	struct Client { sent: i32 }
+	impl Client {
+		async fn send(&mut self) -> i32 {
+			Yield(false).await;
+			self.sent += 1;
+			self.sent
+		}
+		async fn sent(&self) -> i32 {
+			Yield(false).await;
+			self.sent
+		}
+	}
+
+	let mut client = Client { sent: 0 };
+
+	let chain = client
+		.chain_mut_async(Client::send)
+		.chain_async(Client::sent)
+		.chain_mut_async(Client::send)
+		.chain_mut_async(async |client: &mut Client| client.sent *= 10);
+
+	let client: &Client = block_on(chain);
+	assert_eq!(client.sent, 20);
+
+	let client = block_on(client.chain_async(Client::sent).chain_async(Client::sent));
+	assert_eq!(client.sent, 20);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_async() {
+	struct Client { sent: i32 }
+	impl Client {
+		async fn send(&mut self) -> i32 {
+			Yield(false).await;
+			self.sent += 1;
+			self.sent
+		}
+	}
+
+	let mut client = Client { sent: 0 };
+
+	let result = block_on(
+		client
+			.chain_mut_async(Client::send)
+			.chain_mut_async(Client::send)
+			.into_chain_result()
+	).result;
+
+	assert_eq!(result, 2);
+
+	let result = block_on(client.chain_async(async |client: &Client| client.sent * 2).into_chain_result());
+	assert_eq!(result.result, 4);
+}
+
+#[test]
+fn test_chain_macro() {
+	struct Builder { name: &'static str, total: i32 }
+	impl Builder {
+		fn set_name(&mut self, name: &'static str) {
+			self.name = name;
+		}
+		fn add(&mut self, a: i32, b: i32) {
+			self.total += a + b;
+		}
+		fn reset(&mut self) {
+			self.total = 0;
+		}
+		fn check(&self, total: i32) {
+			assert_eq!(self.total, total);
+		}
+		fn name(&self) -> &str {
+			self.name
+		}
+	}
+
+	let mut builder = Builder { name: "", total: 0 };
+	let this = 10;
+
+	chain!(builder =>
+		add(1, 2),
+		&check(3),
+		reset,
+		set_name("chainer"),
+		&name,
+		add(this, 5,),
+		check(15),
+	);
+
+	assert_eq!(builder.name, "chainer");
+	assert_eq!(builder.total, 15);
+
+	let builder = builder;
+
+	chain!(&builder => &check(15), check(15), &name);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_chain_macro() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn add(&mut self, amount: i32) -> i32 {
+			self.value += amount;
+			self.value
+		}
+		fn get(&self) -> i32 {
+			self.value
+		}
+	}
+
+	let mut counter = Counter { value: 0 };
+
+	let result = chain_result!(counter => add(1), &get, add(2)).result;
+
+	assert_eq!(result, 3);
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn test_chainable() {
+	struct Wrapper<T> { value: T, reads: core::cell::Cell<usize> }
+
+	#[chainable]
+	impl<T: Clone + PartialEq> Wrapper<T> {
+		fn new(value: T) -> Self {
+			Wrapper { value, reads: core::cell::Cell::new(0) }
+		}
+		fn set(&mut self, value: T) -> T {
+			core::mem::replace(&mut self.value, value)
+		}
+		fn replace_with<F: FnOnce(&T) -> T>(&mut self, f: F) {
+			self.value = f(&self.value);
+		}
+		fn is(&self, value: &T) -> bool {
+			self.reads.set(self.reads.get() + 1);
+			self.value == *value
+		}
+		fn merge(&mut self, other: Self) {
+			self.value = other.value;
+		}
+		fn value(&self) -> &T {
+			self.reads.set(self.reads.get() + 1);
+			&self.value
+		}
+		#[allow(unknown_lints, mismatched_lifetime_syntaxes)]
+		fn iter(&self) -> core::slice::Iter<T> {
+			core::slice::from_ref(&self.value).iter()
+		}
+		fn check(&self, value: T) -> Result<(), T> {
+			if self.value == value { Ok(()) } else { Err(value) }
+		}
+	}
+
+	let mut wrapper = Wrapper::new(1);
+
+	wrapper
+		.chain_set(2)
+		.chain_replace_with(|value| value * 10)
+		.chain_merge(Wrapper::new(30))
+		.chain_is(&30)
+		.chain_value()
+		.chain_iter()
+		.chain_check(30);
+
+	assert_eq!(wrapper.value, 30);
+	assert_eq!(wrapper.reads.get(), 2);
+}
+
+#[cfg(all(feature = "macros", feature = "results"))]
+#[test]
+fn test_results_chainable() {
+	struct Counter { value: i32, name: &'static str }
+
+	#[chainable(pub(crate))]
+	impl Counter {
+		fn add(&mut self, amount: i32) -> i32 {
+			self.value += amount;
+			self.value
+		}
+		fn get(&self) -> i32 {
+			self.value
+		}
+		fn name(&self) -> &str {
+			self.name
+		}
+		fn value_mut(&mut self) -> &mut i32 {
+			&mut self.value
+		}
+	}
+
+	let mut counter = Counter { value: 0, name: "counter" };
+
+	let result = counter
+		.chain_add_result(1)
+		.chain_add_result(2)
+		.into_result();
+
+	assert_eq!(result, 3);
+
+	let result = counter
+		.chain_add_result(3)
+		.chain_get_result()
+		.result;
+
+	assert_eq!(result, 6);
+
+	let result = counter
+		.chain_get_result()
+		.chain_get_result()
+		.result;
+
+	assert_eq!(result, 6);
+
+	let result = counter
+		.chain_add_result(1)
+		.chain_get_result()
+		.chain_add_result(1)
+		.into_result();
+
+	assert_eq!(result, 8);
+
+	let name = counter.chain_add_result(1).chain_name_result().result;
+
+	assert_eq!(name, "counter");
+
+	*counter.chain_add_result(1).chain_value_mut_result() += 1;
+
+	assert_eq!(counter.value, 11);
+}
+
+mod prelude {
+	use crate::prelude::*;
+
+	#[test]
+	fn test_prelude() {
+		struct Counter { value: i32 }
+		impl Counter {
+			fn increment(&mut self) {
+				self.value += 1;
+			}
+			fn get(&self) -> Option<i32> {
+				Some(self.value)
+			}
+		}
+
+		let mut counter = Counter { value: 0 };
+
+		counter.chain_mut(Counter::increment);
+
+		counter
+			.chain_shared(Counter::get)
+			.chain_if(true, Counter::get);
+
+		let value = counter
+			.try_chain(Counter::get)
+			.into_result()
+			.map(|counter| counter.value);
+
+		assert_eq!(value, Ok(1));
+
+		let mut numbers = [1, 2].iter().chain(&[3]);
+		let numbers = (&mut numbers).chain(&[4]);
+
+		assert_eq!(numbers.sum::<i32>(), 10);
+	}
+
+	#[test]
+	fn test_prelude_with_call_chain() {
+		use crate::CallChain;
+
+		struct Counter { value: Option<i32> }
+
+		let counter = Counter { value: Some(1) };
+
+		let value = counter
+			.chain(|counter| counter.value)
+			.chain_if(true, |counter| counter.value)
+			.try_chain(|counter| counter.value)
+			.into_result()
+			.map(|counter| counter.value);
+
+		assert_eq!(value, Ok(Some(1)));
+	}
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn test_derive_chainable() {
+	#[derive(Chainable)]
+	struct Wrapper<T> { value: T }
+
+	fn increment<T: Chainable + ?Sized>(chainable: &mut T, f: impl FnOnce(&mut T)) {
+		chainable.chain_mut(f);
+	}
+
+	let mut wrapper = Wrapper { value: 1 };
+	increment(&mut wrapper, |wrapper| wrapper.value += 1);
+
+	assert_eq!(wrapper.value, 2);
+}
+
+#[test]
+fn test_owned() {
+	struct Builder { name: &'static str, size: usize }
+	impl Builder {
+		fn name(self, name: &'static str) -> Self {
+			Builder { name, ..self }
+		}
+		fn grow(&mut self) -> usize {
+			self.size += 1;
+			self.size
+		}
+	}
+
+	let builder = Builder { name: "", size: 0 }
+		.chain_owned(Builder::grow)
+		.chain_into(|builder| builder.name("chainer"))
+		.chain_owned(Builder::grow);
+
+	assert_eq!(builder.name, "chainer");
+	assert_eq!(builder.size, 2);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_owned() {
+	struct Builder { name: &'static str, size: usize }
+	impl Builder {
+		fn name(self, name: &'static str) -> Self {
+			Builder { name, ..self }
+		}
+		fn grow(&mut self) -> usize {
+			self.size += 1;
+			self.size
+		}
+	}
+
+	let (builder, size): (Builder, usize) = Builder { name: "", size: 0 }
+		.chain_owned_result(Builder::grow)
+		.chain_into(|builder| builder.name("chainer"))
+		.chain_owned_result(Builder::grow)
+		.chain_into(|builder| builder.name("chained"))
+		.into();
+
+	assert_eq!(builder.name, "chained");
+	assert_eq!(size, 2);
+}
+
+#[test]
+fn test_pipe() {
+	struct Celsius(f64);
+	impl Celsius {
+		fn to_fahrenheit(&self) -> f64 {
+			self.0 * 9.0 / 5.0 + 32.0
+		}
+	}
+	impl AsRef<f64> for Celsius {
+		fn as_ref(&self) -> &f64 {
+			&self.0
+		}
+	}
+
+	let fahrenheit = 100.0
+		.pipe(Celsius)
+		.pipe_ref(Celsius::to_fahrenheit);
+
+	assert_eq!(fahrenheit, 212.0);
+
+	let celsius = Celsius(10.0).pipe_as_ref(|value: &f64| *value);
+	assert_eq!(celsius, 10.0);
+
+	let mut counter = 0;
+	let previous = counter.pipe_mut(|counter| core::mem::replace(counter, 5));
+	assert_eq!((previous, counter), (0, 5));
+
+	let len = "chainer".pipe_borrow(str::len);
+	assert_eq!(len, 7);
+
+	let cell = core::cell::RefCell::new(3);
+	let value = cell.borrow().pipe_deref(|value: &i32| value * 2);
+	assert_eq!(value, 6);
+}
+
+#[test]
+fn test_guard_refcell() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) -> i32 {
+			self.value += 1;
+			self.value
+		}
+	}
+
+	let counter = core::cell::RefCell::new(Counter { value: 0 });
+
+	counter
+		.chain_borrow_mut()
 		.chain_mut(Counter::increment)
+		.chain(|counter| assert_eq!(counter.value, 1))
+		.chain_mut(Counter::increment);
+
+	counter
+		.chain_borrow()
+		.chain(|counter| assert_eq!(counter.value, 2))
+		.chain(|_| assert!(counter.try_borrow_mut().is_err()));
+
+	assert_eq!(counter.borrow_mut().increment(), 3);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_guard_refcell() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) -> i32 {
+			self.value += 1;
+			self.value
+		}
+	}
+
+	let counter = core::cell::RefCell::new(Counter { value: 0 });
+
+	let result = counter
+		.chain_borrow_mut()
+		.chain_mut_result(Counter::increment)
+		.chain_mut_result(Counter::increment)
+		.into_result();
+
+	assert_eq!(result, 2);
+
+	let (guard, result) = counter
+		.chain_borrow()
+		.chain_result(|counter| counter.value * 10)
+		.into_parts();
+
+	assert!(counter.try_borrow_mut().is_err());
+	drop(guard);
+	assert_eq!(result, 20);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_guard_sync() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) {
+			self.value += 1;
+		}
+	}
+
+	let counter = std::sync::Mutex::new(Counter { value: 0 });
+
+	counter
+		.chain_lock()
+		.unwrap()
 		.chain_mut(Counter::increment)
+		.chain_mut(Counter::increment);
+
+	assert_eq!(counter.lock().unwrap().value, 2);
+
+	let counter = std::sync::RwLock::new(Counter { value: 0 });
+
+	counter
+		.chain_write()
+		.unwrap()
+		.chain_mut(Counter::increment);
+
+	counter
+		.chain_read()
+		.unwrap()
+		.chain(|counter| assert_eq!(counter.value, 1))
+		.chain(|_| assert!(counter.try_write().is_err()));
+}
+
+#[test]
+fn test_focus() {
+	struct Tls { port: u16 }
+	struct Inner { tls: Tls, retries: u8 }
+	struct Config { inner: Inner, name: &'static str }
+
+	let mut config = Config { inner: Inner { tls: Tls { port: 0 }, retries: 0 }, name: "" };
+
+	config
+		.focus_mut(|config| &mut config.inner.tls)
+		.chain_mut(|tls| tls.port = 443)
+		.chain(|tls| assert_eq!(tls.port, 443))
+		.back()
+		.focus_mut(|config| &mut config.inner.retries)
+		.chain_mut(|retries| *retries += 3)
+		.back()
+		.chain_mut(|config| config.name = "chainer");
+
+	assert_eq!(config.inner.tls.port, 443);
+	assert_eq!(config.inner.retries, 3);
+	assert_eq!(config.name, "chainer");
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_focus() {
+	struct Inner { values: [i32; 2] }
+	struct Outer { inner: Inner, sum: i32 }
+
+	let mut outer = Outer { inner: Inner { values: [1, 2] }, sum: 0 };
+
+	let sum = outer
+		.focus_mut(|outer| &mut outer.inner)
+		.chain_mut(|inner| inner.values[0] = 10)
+		.chain_result(|inner| inner.values[0] + inner.values[1])
+		.unfocus()
+		.chain_mut_with(|outer, sum| outer.sum = sum)
+		.chain_result(|outer| outer.sum)
+		.result;
+
+	assert_eq!(sum, 12);
+	assert_eq!(outer.inner.values, [10, 2]);
+}
+
+#[derive(Default)]
+struct Trace {
+	before: usize,
+	after: [(usize, bool); 4],
+	len: usize
+}
+
+impl ChainObserver for Trace {
+	fn before_step(&mut self, step: usize, name: &'static str) {
+		assert_eq!(step, self.before);
+		assert!(name.contains("test_observe"));
+		self.before += 1;
+	}
+
+	fn after_step(&mut self, step: usize, _name: &'static str, panicked: bool) {
+		self.after[self.len] = (step, panicked);
+		self.len += 1;
+	}
+}
+
+#[test]
+fn test_observe() {
+	struct Counter { value: i32 }
+
+	let mut counter = Counter { value: 0 };
+
+	let trace = counter
+		.observe_mut(Trace::default())
+		.chain_mut(|counter| counter.value += 1)
+		.chain(|counter| assert_eq!(counter.value, 1))
+		.chain_mut(|counter| counter.value += 1)
+		.into_observer();
+
+	assert_eq!(counter.value, 2);
+	assert_eq!(trace.before, 3);
+	assert_eq!(&trace.after[..trace.len], [(0, false), (1, false), (2, false)]);
+
+	let mut trace = Trace::default();
+	counter
+		.observe(&mut trace)
+		.chain(|counter| assert_eq!(counter.value, 2));
+
+	assert_eq!(&trace.after[..trace.len], [(0, false)]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_observe_panic() {
+	struct Counter { value: i32 }
+
+	let mut counter = Counter { value: 0 };
+	let mut trace = Trace::default();
+
+	let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+		counter
+			.observe_mut(&mut trace)
+			.chain_mut(|counter| counter.value += 1)
+			.chain_mut(|_| panic!("step failed"));
+	}));
+
+	assert!(panicked.is_err());
+	assert_eq!(&trace.after[..trace.len], [(0, false), (1, true)]);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_traits() {
+	extern crate std;
+	use std::{collections::HashSet, format, string::ToString};
+
+	struct Counter { value: i32 }
+
+	let counter = Counter { value: 1 };
+	let mut other = Counter { value: 2 };
+
+	let a = counter.chain_result(|counter| counter.value);
+	let b = a;
+	assert_eq!(a, b);
+	assert!(a < other.chain_result(|other| other.value));
+	let mut third = Counter { value: 3 };
+	assert_eq!(other.chain_mut_result(|other| other.value), third.chain_mut_result(|third| third.value - 1));
+
+	assert_eq!(format!("{a:?}"), "CallChainResult { result: 1, .. }");
+	assert_eq!(a.to_string(), "1");
+	assert_eq!([a, b, a.chain_result(|counter| counter.value)].into_iter().collect::<HashSet<_>>().len(), 1);
+
+	let (subject, result): (&Counter, i32) = a.into();
+	assert_eq!((subject.value, result), (1, 1));
+
+	let (subject, result): (&mut Counter, i32) = other.chain_mut_result(|other| other.value).into();
+	subject.value += result;
+	assert_eq!(other.value, 4);
+}
+
+#[test]
+fn test_inspect() {
+	#[derive(Debug)]
+	struct Counter { value: i32 }
+
+	let mut counter = Counter { value: 0 };
+	let mut inspected = 0;
+
+	counter
+		.chain_mut(|counter| counter.value += 1)
+		.chain_mut_debug_only(|counter| inspected = counter.value)
+		.chain_mut(|counter| counter.value += 1);
+
+	#[cfg(feature = "std")]
+	counter.chain_mut_dbg().chain_mut(|counter| counter.value += 1).chain_dbg();
+
+	assert_eq!(inspected, if cfg!(debug_assertions) { 1 } else { 0 });
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_inspect() {
+	#[derive(Debug)]
+	struct Counter { value: i32 }
+
+	let mut counter = Counter { value: 0 };
+	let mut inspected = 0;
+
+	let chain = counter
+		.chain_mut_result(|counter| { counter.value += 1; counter.value })
+		.chain_debug_only(|value| inspected = *value);
+
+	let value = chain.chain_mut_result(|counter| { counter.value += 1; counter.value });
+
+	#[cfg(feature = "std")]
+	let value = value.chain_dbg();
+
+	assert_eq!(value.into_result(), 2);
+	assert_eq!(inspected, if cfg!(debug_assertions) { 1 } else { 0 });
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_catch() {
+	struct Counter { value: u8 }
+
+	let mut counter = Counter { value: 0 };
+
+	let (_, panics) = counter
+		.chain_mut_catch(CatchPolicy::Continue, |counter| counter.value += 1)
+		.chain_mut_catch(|_| panic!("first"))
+		.chain_catch(|counter| assert_eq!(counter.value, 1))
+		.chain_mut_catch(|_| std::panic::panic_any(7_u32))
+		.chain_mut_catch(|counter| counter.value += 1)
+		.into_parts();
+
+	assert_eq!(counter.value, 2);
+	assert_eq!(panics.iter().map(|panic| panic.step).collect::<std::vec::Vec<_>>(), [1, 3]);
+	assert_eq!(panics[0].message(), Some("first"));
+	assert_eq!(panics[1].message(), None);
+	assert_eq!(panics[1].payload.downcast_ref::<u32>(), Some(&7));
+	assert_eq!(std::string::ToString::to_string(&panics[0]), "call chain panicked at step 1: first");
+
+	let panics = counter
+		.chain_catch(CatchPolicy::Stop, |_| panic!("stop"))
+		.chain_catch(|_| unreachable!())
+		.into_result()
+		.err()
+		.unwrap();
+
+	assert_eq!(panics.len(), 1);
+	assert!(counter.chain_mut_catch(CatchPolicy::Stop, |counter| counter.value += 1).into_result().is_ok());
+	assert_eq!(counter.value, 3);
+}
+
+#[cfg(all(feature = "std", feature = "results"))]
+#[test]
+fn test_results_catch() {
+	struct Counter { value: u8 }
+
+	fn checked(counter: &Counter) -> &Counter {
+		counter
+			.chain_result(|counter| counter.value)
+			.chain_catch(CatchPolicy::Continue, |counter| counter.value.checked_add(1).unwrap())
+			.into_parts()
+			.0
+	}
+
+	assert_eq!(checked(&Counter { value: 1 }).value, 1);
+
+	let mut counter = Counter { value: 0 };
+
+	let value = counter
+		.chain_mut_catch(CatchPolicy::Stop, |counter| { counter.value += 1; counter.value })
+		.chain_catch(|counter| counter.value * 10)
+		.into_chain_result()
+		.unwrap()
+		.into_result();
+
+	assert_eq!(value, 10);
+
+	let chain = counter.chain_mut_result(|counter| counter.value);
+	let panics = chain
+		.chain_mut_catch(CatchPolicy::Stop, |counter| counter.value += 1)
+		.chain_mut_catch(|_| -> u8 { panic!("failed") })
+		.into_chain_result()
+		.err()
+		.unwrap();
+
+	assert_eq!(panics[0].step, 1);
+	assert_eq!(counter.value, 2);
+}
+
+#[test]
+fn test_transaction() {
+	#[derive(Clone)]
+	struct Counter { value: u8, steps: u8 }
+	impl Counter {
+		fn add(&mut self, amount: u8) -> Result<(), &'static str> {
+			self.steps += 1;
+			self.value = self.value.checked_add(amount).ok_or("overflow")?;
+			Ok(())
+		}
+	}
+
+	let mut counter = Counter { value: 0, steps: 0 };
+
+	let error = counter
+		.transaction(|tx| tx.try_chain_mut(|counter| counter.add(200)).try_chain_mut(|counter| counter.add(100)))
+		.into_result()
+		.err()
+		.unwrap();
+
+	assert_eq!(error, ChainError { step: 1, error: "overflow" });
+	assert_eq!((counter.value, counter.steps), (0, 0));
+
+	counter
+		.transaction(|tx| tx.try_chain_mut(|counter| counter.add(1)))
+		.try_chain_mut(|counter| counter.add(1))
+		.into_result()
+		.ok()
+		.unwrap();
+
+	assert_eq!((counter.value, counter.steps), (2, 2));
+
+	struct Log { entries: [u8; 4], len: usize }
+	impl Snapshot for Log {
+		type Snapshot = usize;
+
+		fn snapshot(&self) -> usize {
+			self.len
+		}
+
+		fn restore(&mut self, len: usize) {
+			self.len = len;
+		}
+	}
+
+	let mut log = Log { entries: [0; 4], len: 0 };
+	let result = log
+		.transaction_snapshot(|tx| {
+			tx.chain_mut(|log| {
+				log.entries[log.len] = 1;
+				log.len += 1;
+			})
+			.try_chain_mut(|_| None::<()>)
+		})
+		.into_result();
+
+	assert!(result.is_err());
+	assert_eq!(log.len, 0);
+	assert_eq!(log.entries[0], 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_transaction_panic() {
+	#[derive(Clone)]
+	struct Counter { value: u8 }
+
+	let mut counter = Counter { value: 0 };
+
+	let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+		let _ = counter.transaction(|tx| tx.chain_mut(|counter| counter.value = 100).try_chain_mut(|_| -> Option<()> { panic!("step failed") }));
+	}));
+
+	assert!(panicked.is_err());
+	assert_eq!(counter.value, 0);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_transaction() {
+	#[derive(Clone)]
+	struct Counter { value: u8 }
+
+	let mut counter = Counter { value: 0 };
+
+	let chain = counter.chain_mut_result(|counter| counter.value = 10);
+	let result = chain
+		.transaction(|tx| tx.try_chain_mut(|counter| { counter.value = 20; counter.value.checked_mul(20) }))
+		.into_result();
+
+	assert_eq!(result, Err(ChainError { step: 0, error: NoneError }));
+	assert_eq!(counter.value, 10);
+
+	let chain = counter.chain_mut_result(|_| ());
+	let result = chain
+		.transaction(|tx| tx.try_chain_mut(|counter| { counter.value += 1; Ok::<_, ()>(counter.value) }))
+		.into_result();
+
+	assert_eq!(result, Ok(11));
+	assert_eq!(counter.value, 11);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_mixed() {
+	struct Counter { value: i32 }
+	impl Counter {
+		fn increment(&mut self) -> i32 {
+			self.value += 1;
+			self.value
+		}
+		fn doubled(&self) -> i32 {
+			self.value * 2
+		}
+	}
+
+	let mut counter = Counter { value: 0 };
+
+	let result = counter
+		.chain_mut_result(Counter::increment)
+		.chain_result(Counter::doubled)
+		.chain_mut_result(Counter::increment)
+		.chain_result_if(true, Counter::doubled)
+		.chain_mut_result(Counter::increment)
 		.result;
 
 	assert_eq!(result, 3);
-}
\ No newline at end of file
+	assert_eq!(counter.value, 3);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_ref() {
+	struct User { name: &'static str, tags: [u8; 2] }
+	impl User {
+		fn name(&self) -> &&'static str {
+			&self.name
+		}
+		fn tags(&self) -> &[u8] {
+			&self.tags
+		}
+		fn tags_mut(&mut self) -> &mut [u8] {
+			&mut self.tags
+		}
+	}
+
+	let mut user = User { name: "ferris", tags: [1, 2] };
+
+	let tags: &[u8] = user.chain_result(|user| user.tags.len()).chain_ref(User::tags).result;
+	let name = user.chain_ref(User::name).chain_result(|user| user.tags.len()).chain_ref(User::name).result;
+	assert_eq!((tags, *name), (&[1, 2][..], "ferris"));
+
+	user.chain_mut_ref(User::tags_mut)[0] = 3;
+
+	let tags = user
+		.chain_mut_result(|user| user.tags[1] = 4)
+		.chain_result(|user| user.tags.len())
+		.chain_ref(User::tags)
+		.result;
+	assert_eq!(tags, [3, 4]);
+
+	user.chain_mut_result(|user| user.tags.reverse()).chain_mut_ref(User::tags_mut)[1] = 5;
+	assert_eq!(user.tags, [4, 5]);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_subject() {
+	struct Counter { value: i32 }
+
+	fn keep(counter: &Counter) -> &Counter {
+		counter.chain_result(|counter| counter.value).subject()
+	}
+
+	let mut counter = Counter { value: 1 };
+	assert_eq!(keep(&counter).value, 1);
+
+	let (subject, value) = counter.chain_result(|counter| counter.value + 1).into_parts();
+	assert_eq!((subject.value, value), (1, 2));
+	assert_eq!(counter.chain_result(|_| ()).into_subject().value, 1);
+
+	let mut chain = counter.chain_mut_result(|counter| counter.value += 1);
+	chain.subject_mut().value += 1;
+	assert_eq!(chain.subject().value, 3);
+
+	let subject = chain.into_subject();
+	subject.value += 1;
+
+	let (subject, previous) = counter.chain_mut_result(|counter| core::mem::replace(&mut counter.value, 0)).into_parts();
+	subject.value = previous * 2;
+	assert_eq!(counter.value, 8);
+
+	let subject = counter
+		.chain_mut_result(|counter| counter.value += 1)
+		.chain_mut_result(|counter| counter.value *= 2)
+		.into_subject();
+	subject.value += 1;
+	assert_eq!(counter.value, 19);
+}
+
+#[cfg(feature = "results")]
+#[test]
+fn test_results_rechain() {
+	struct Inventory { items: [u8; 3] }
+	impl Inventory {
+		fn total(&self) -> u32 {
+			self.items.iter().map(|&item| u32::from(item)).sum()
+		}
+	}
+
+	let mut inventory = Inventory { items: [1, 2, 3] };
+
+	let rechained = inventory
+		.chain_result(Inventory::total)
+		.rechain()
+		.chain_mut(|total| *total *= 10)
+		.chain_result(|total| total + 1);
+
+	assert_eq!((*rechained.subject(), *rechained.result()), (60, 61));
+
+	let (subject, total) = rechained.back().chain_result(|inventory| inventory.items.len()).into_parts();
+	assert_eq!((subject.items.len(), total), (3, 3));
+
+	let total = inventory
+		.chain_mut_result(|inventory| inventory.items[0] = 4)
+		.chain_result(Inventory::total)
+		.rechain()
+		.chain_mut_result(core::mem::take)
+		.back()
+		.chain_mut_with(|inventory, total| inventory.items[2] = total as u8)
+		.into_subject()
+		.total();
+
+	assert_eq!(total, 6);
+
+	let mut chain = inventory.chain_result(Inventory::total);
+	chain.rechain_mut().chain_mut_result(|total| *total += 1).chain_mut_result(|total| *total *= 2);
+	assert_eq!(chain.into_result(), 14);
+
+	let mut chain = inventory.chain_mut_result(|inventory| inventory.items);
+	chain.rechain_mut().chain_mut_result(|items| items.reverse());
+	assert_eq!(chain.into_result(), [0, 2, 4]);
+}
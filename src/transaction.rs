@@ -0,0 +1,87 @@
+use crate::{ChainError, TryCallChainMut};
+
+/// A cheaper alternative to [`Clone`] for snapshotting the chained subject of a transaction, such as copying only the fields a transaction can modify. Used by [`CallChainMut::transaction_snapshot`](crate::CallChainMut::transaction_snapshot).
+///
+/// # Example
+///
+/// ```rust
+/// use chainer::*;
+///
+/// struct Account { balance: u32, history: Vec<u32> }
+/// impl Account {
+///     fn withdraw(&mut self, amount: u32) -> Result<(), &'static str> {
+///         self.balance = self.balance.checked_sub(amount).ok_or("insufficient funds")?;
+///         Ok(())
+///     }
+/// }
+///
+/// impl Snapshot for Account {
+///     type Snapshot = (u32, usize);
+///
+///     fn snapshot(&self) -> (u32, usize) {
+///         (self.balance, self.history.len())
+///     }
+///
+///     fn restore(&mut self, (balance, history): (u32, usize)) {
+///         self.balance = balance;
+///         self.history.truncate(history);
+///     }
+/// }
+///
+/// fn main() {
+///     let mut account = Account { balance: 100, history: vec![] };
+///
+///     let result = account
+///         .transaction_snapshot(|tx| {
+///             tx.chain_mut(|account| account.history.push(150))
+///                 .try_chain_mut(|account| account.withdraw(150))
+///         })
+///         .into_result();
+///
+///     assert!(result.is_err());
+///     assert_eq!(account.balance, 100);
+///     assert!(account.history.is_empty());
+/// }
+/// ```
+pub trait Snapshot {
+	/// The snapshot of the subject.
+	type Snapshot;
+
+	/// Takes a snapshot of the subject before a transaction.
+	fn snapshot(&self) -> Self::Snapshot;
+
+	/// Restores the subject from a snapshot after a failed transaction.
+	fn restore(&mut self, snapshot: Self::Snapshot);
+}
+
+/// Restores the subject when dropped, unless the transaction committed. Restoring on drop also covers a transaction which panics.
+struct Rollback<'a, S: ?Sized, P, F: FnOnce(&mut S, P)> {
+	this: &'a mut S,
+	rollback: Option<(P, F)>
+}
+
+impl<S: ?Sized, P, F: FnOnce(&mut S, P)> Drop for Rollback<'_, S, P, F> {
+	#[inline]
+	fn drop(&mut self) {
+		if let Some((snapshot, restore)) = self.rollback.take() {
+			restore(self.this, snapshot);
+		}
+	}
+}
+
+/// Runs the fallible call chain returned by `f`, restoring the subject from `snapshot` if it fails or panics.
+pub(crate) fn run<S: ?Sized, P, R, E, F>(this: &mut S, snapshot: P, restore: impl FnOnce(&mut S, P), f: F) -> (usize, Result<R, ChainError<E>>)
+where
+	F: for<'t> FnOnce(&'t mut S) -> TryCallChainMut<'t, S, R, E>
+{
+	let mut rollback = Rollback {
+		this,
+		rollback: Some((snapshot, restore))
+	};
+
+	let TryCallChainMut { step, result, .. } = f(&mut *rollback.this);
+	if result.is_ok() {
+		rollback.rollback = None;
+	}
+	(step, result)
+}